@@ -0,0 +1,140 @@
+// Compares `SgMap` against `heapless::FnvIndexMap`, both fixed-capacity and stack-allocated.
+//
+// Run with `cargo bench --bench heapless_cmp`.
+//
+// Methodology:
+//
+// * Both maps are instantiated at the same capacity `N`, with `u32` keys and values. `FnvIndexMap` requires a
+//   power-of-two capacity, so only powers of two are measured.
+// * Keys are a fixed, seeded shuffle of `0..N`, identical for both maps. Every run sees the same access pattern.
+// * Three operations are timed: `insert` (fill an empty map to capacity), `get` (look up every key once), and
+//   `iter` (visit every entry once). Map construction is included in `insert` but not in `get`/`iter`.
+// * Each operation is warmed up, then sampled `SAMPLES` times. The median sample is reported, divided by `N` to
+//   give nanoseconds per element. The median is less sensitive to scheduler noise than the mean.
+// * Stack footprint is `size_of` the map type - the entire storage, since neither map allocates.
+// * `black_box` keeps the optimizer from eliding work whose result is otherwise unused.
+//
+// Note `FnvIndexMap` is hash-based and unordered, whereas `SgMap` keeps keys sorted: the comparison measures the cost
+// of ordering, not two equivalent data structures.
+//
+// Output is CSV on stdout (`impl,op,capacity,ns_per_elem,stack_bytes`), one row per measurement, so that results
+// can be saved and diffed across commits to track regressions.
+
+use std::hint::black_box;
+use std::mem::size_of;
+use std::time::{Duration, Instant};
+
+use buggy_scapegoat::SgMap;
+use heapless::index_map::FnvIndexMap;
+use rand::{rngs::SmallRng, seq::SliceRandom, SeedableRng};
+
+const SAMPLES: usize = 51;
+const WARMUP: usize = 5;
+const SEED: u64 = 0x5eed;
+
+// Time `op` repeatedly, returning the median sample.
+fn median_time<F: FnMut()>(mut op: F) -> Duration {
+    for _ in 0..WARMUP {
+        op();
+    }
+
+    let mut samples: Vec<Duration> = (0..SAMPLES)
+        .map(|_| {
+            let start = Instant::now();
+            op();
+            start.elapsed()
+        })
+        .collect();
+
+    samples.sort_unstable();
+    samples[SAMPLES / 2]
+}
+
+fn report(name: &str, op: &str, capacity: usize, elapsed: Duration, stack_bytes: usize) {
+    let ns_per_elem = elapsed.as_nanos() as f64 / capacity as f64;
+    println!("{name},{op},{capacity},{ns_per_elem:.2},{stack_bytes}");
+}
+
+fn shuffled_keys<const N: usize>() -> Vec<u32> {
+    let mut keys: Vec<u32> = (0..N as u32).collect();
+    keys.shuffle(&mut SmallRng::seed_from_u64(SEED));
+    keys
+}
+
+fn bench_sg_map<const N: usize>(keys: &[u32]) {
+    let name = "SgMap";
+    let stack_bytes = size_of::<SgMap<u32, u32, N>>();
+
+    let elapsed = median_time(|| {
+        let mut map = SgMap::<u32, u32, N>::new();
+        for k in keys {
+            map.insert(*k, *k);
+        }
+        black_box(&map);
+    });
+    report(name, "insert", N, elapsed, stack_bytes);
+
+    let map: SgMap<u32, u32, N> = keys.iter().map(|k| (*k, *k)).collect();
+
+    let elapsed = median_time(|| {
+        for k in keys {
+            black_box(map.get(black_box(k)));
+        }
+    });
+    report(name, "get", N, elapsed, stack_bytes);
+
+    let elapsed = median_time(|| {
+        black_box(
+            map.iter()
+                .fold(0_u64, |acc, (k, v)| acc + u64::from(*k ^ *v)),
+        );
+    });
+    report(name, "iter", N, elapsed, stack_bytes);
+}
+
+fn bench_index_map<const N: usize>(keys: &[u32]) {
+    let name = "FnvIndexMap";
+    let stack_bytes = size_of::<FnvIndexMap<u32, u32, N>>();
+
+    let elapsed = median_time(|| {
+        let mut map = FnvIndexMap::<u32, u32, N>::new();
+        for k in keys {
+            map.insert(*k, *k).unwrap();
+        }
+        black_box(&map);
+    });
+    report(name, "insert", N, elapsed, stack_bytes);
+
+    let mut map = FnvIndexMap::<u32, u32, N>::new();
+    for k in keys {
+        map.insert(*k, *k).unwrap();
+    }
+
+    let elapsed = median_time(|| {
+        for k in keys {
+            black_box(map.get(black_box(k)));
+        }
+    });
+    report(name, "get", N, elapsed, stack_bytes);
+
+    let elapsed = median_time(|| {
+        black_box(
+            map.iter()
+                .fold(0_u64, |acc, (k, v)| acc + u64::from(*k ^ *v)),
+        );
+    });
+    report(name, "iter", N, elapsed, stack_bytes);
+}
+
+fn bench_capacity<const N: usize>() {
+    let keys = shuffled_keys::<N>();
+    bench_sg_map::<N>(&keys);
+    bench_index_map::<N>(&keys);
+}
+
+fn main() {
+    println!("impl,op,capacity,ns_per_elem,stack_bytes");
+    bench_capacity::<64>();
+    bench_capacity::<256>();
+    bench_capacity::<1024>();
+}
@@ -0,0 +1,57 @@
+#![no_main]
+
+use std::collections::BTreeMap;
+
+use buggy_scapegoat::SgMap;
+use libfuzzer_sys::{
+    arbitrary::{self, Arbitrary},
+    fuzz_target,
+};
+
+// Every `u8` key fits, so inserts can't fail for lack of capacity
+const CAPACITY: usize = 256;
+
+#[derive(Arbitrary, Debug)]
+enum MapMethod<K: Ord, V> {
+    Insert { key: K, val: V },
+    Remove { key: K },
+    Get { key: K },
+    PopFirst,
+    PopLast,
+    Clear,
+}
+
+// Differential test: each op must have the same result for `SgMap` and `BTreeMap`,
+// and leave the `SgMap` in a structurally valid state.
+fuzz_target!(|methods: Vec<MapMethod<u8, u8>>| {
+    let mut sg_map = SgMap::<u8, u8, CAPACITY>::new();
+    let mut bt_map = BTreeMap::new();
+
+    for m in methods {
+        match m {
+            MapMethod::Insert { key, val } => {
+                assert_eq!(sg_map.insert(key, val), bt_map.insert(key, val));
+            }
+            MapMethod::Remove { key } => {
+                assert_eq!(sg_map.remove(&key), bt_map.remove(&key));
+            }
+            MapMethod::Get { key } => {
+                assert_eq!(sg_map.get(&key), bt_map.get(&key));
+            }
+            MapMethod::PopFirst => {
+                assert_eq!(sg_map.pop_first(), bt_map.pop_first());
+            }
+            MapMethod::PopLast => {
+                assert_eq!(sg_map.pop_last(), bt_map.pop_last());
+            }
+            MapMethod::Clear => {
+                sg_map.clear();
+                bt_map.clear();
+            }
+        }
+
+        sg_map.assert_invariants();
+        assert_eq!(sg_map.len(), bt_map.len());
+        assert!(sg_map.iter().eq(bt_map.iter()));
+    }
+});
@@ -0,0 +1,81 @@
+use crate::{SgMap, SgSet};
+
+/// Common interface for [`SgMap`] and [`SgSet`] of any capacity, for bounding generic code.
+/// This trait is sealed and cannot be implemented outside of this crate.
+///
+/// # Examples
+///
+/// ```
+/// use buggy_scapegoat::{OrderedCapacity, SgMap, SgSet};
+///
+/// fn capacity_of<C: OrderedCapacity>(_: &C) -> usize {
+///     C::CAPACITY
+/// }
+///
+/// assert_eq!(capacity_of(&SgMap::<u8, char, 10>::new()), 10);
+/// assert_eq!(capacity_of(&SgSet::<u8, 20>::new()), 20);
+/// ```
+pub trait OrderedCapacity: private::Sealed {
+    /// Key type for maps, element type for sets.
+    type Key: Ord + Default;
+
+    /// Value type for maps, `()` for sets.
+    type Value: Default;
+
+    /// Maximum number of items, e.g. the const generic `N`.
+    const CAPACITY: usize;
+}
+
+impl<K: Ord + Default, V: Default, const N: usize> OrderedCapacity for SgMap<K, V, N> {
+    type Key = K;
+    type Value = V;
+    const CAPACITY: usize = N;
+}
+
+impl<T: Ord + Default, const N: usize> OrderedCapacity for SgSet<T, N> {
+    type Key = T;
+    type Value = ();
+    const CAPACITY: usize = N;
+}
+
+mod private {
+    use crate::{SgMap, SgSet};
+
+    pub trait Sealed {}
+
+    impl<K: Ord + Default, V: Default, const N: usize> Sealed for SgMap<K, V, N> {}
+    impl<T: Ord + Default, const N: usize> Sealed for SgSet<T, N> {}
+}
+
+// Test ----------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::OrderedCapacity;
+    use crate::{SgMap, SgSet};
+
+    // Generic over collection type and capacity
+    fn capacity(collection: &impl OrderedCapacity) -> usize {
+        fn capacity_of<C: OrderedCapacity>(_: &C) -> usize {
+            C::CAPACITY
+        }
+
+        capacity_of(collection)
+    }
+
+    #[test]
+    fn test_ordered_capacity() {
+        let mut map = SgMap::<usize, &str, 10>::new();
+        map.insert(1, "a");
+        let set: SgSet<char, 32> = ['a', 'b', 'c'].into_iter().collect();
+
+        assert_eq!(capacity(&map), map.capacity());
+        assert_eq!(capacity(&set), set.capacity());
+        assert_eq!(capacity(&map), 10);
+        assert_eq!(capacity(&set), 32);
+
+        // Usable in const contexts
+        let buf = [0_u8; <SgSet<char, 32> as OrderedCapacity>::CAPACITY];
+        assert_eq!(buf.len(), 32);
+    }
+}
@@ -0,0 +1,57 @@
+use core::hash::Hasher;
+
+// CRC-32 (IEEE 802.3) reflected polynomial
+const CRC32_POLY: u32 = 0xedb8_8320;
+
+/// CRC-32 (IEEE 802.3), fed bytes via the [`Hasher`] interface.
+/// Bitwise, no lookup table, to keep code size small.
+pub(crate) struct Crc32 {
+    crc: u32,
+}
+
+impl Crc32 {
+    /// Constructor.
+    pub(crate) fn new() -> Self {
+        Crc32 { crc: u32::MAX }
+    }
+
+    /// Finalized checksum of all bytes written so far.
+    pub(crate) fn checksum(&self) -> u32 {
+        !self.crc
+    }
+}
+
+impl Hasher for Crc32 {
+    fn write(&mut self, bytes: &[u8]) {
+        for b in bytes {
+            self.crc ^= *b as u32;
+            for _ in 0..8 {
+                let mask = (self.crc & 1).wrapping_neg();
+                self.crc = (self.crc >> 1) ^ (CRC32_POLY & mask);
+            }
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.checksum() as u64
+    }
+}
+
+// Test ----------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::Crc32;
+    use core::hash::Hasher;
+
+    #[test]
+    fn test_crc32_check_value() {
+        let mut crc = Crc32::new();
+        assert_eq!(crc.checksum(), 0);
+
+        // Standard check value, split across writes
+        crc.write(b"1234");
+        crc.write(b"56789");
+        assert_eq!(crc.checksum(), 0xcbf4_3926);
+    }
+}
@@ -0,0 +1,67 @@
+// Largest supported `FixedBytes::SIZE`, in bytes.
+pub(crate) const MAX_FIXED_BYTES_SIZE: usize = core::mem::size_of::<u128>();
+
+/// Fixed-size, little-endian byte encoding for plain-old-data types.
+/// Used by [`SgMap::serialize_into`](crate::SgMap::serialize_into) and [`SgMap::deserialize_from`](crate::SgMap::deserialize_from) to persist maps without `alloc`, e.g. to flash.
+///
+/// Implemented for fixed-width integers, so the encoding is identical on every target.
+/// Not for `usize`/`isize`, whose pointer-width encoding would differ between, e.g., a 64-bit host and a 32-bit MCU.
+/// This trait is sealed and cannot be implemented outside of this crate.
+///
+/// # Examples
+///
+/// ```
+/// use buggy_scapegoat::FixedBytes;
+///
+/// let mut buf = [0; 2];
+/// 0xbeef_u16.write_bytes(&mut buf);
+///
+/// assert_eq!(buf, [0xef, 0xbe]);
+/// assert_eq!(u16::read_bytes(&buf), 0xbeef);
+/// ```
+pub trait FixedBytes: Copy + private::Sealed {
+    /// Encoded size, in bytes: between 1 and 16 (the size of a `u128`).
+    const SIZE: usize;
+
+    /// Encode into the first [`SIZE`][FixedBytes::SIZE] bytes of `buf`.
+    /// Caller guarantees `buf` is at least that long.
+    fn write_bytes(&self, buf: &mut [u8]);
+
+    /// Decode from the first [`SIZE`][FixedBytes::SIZE] bytes of `buf`.
+    /// Caller guarantees `buf` is at least that long.
+    fn read_bytes(buf: &[u8]) -> Self;
+}
+
+macro_rules! impl_fixed_bytes {
+    ($($t:ty),*) => {
+        $(
+            impl private::Sealed for $t {}
+
+            // Encodings fit a `MAX_FIXED_BYTES_SIZE` scratch buffer
+            const _: () = assert!(
+                (core::mem::size_of::<$t>() > 0)
+                    && (core::mem::size_of::<$t>() <= MAX_FIXED_BYTES_SIZE)
+            );
+
+            impl FixedBytes for $t {
+                const SIZE: usize = core::mem::size_of::<$t>();
+
+                fn write_bytes(&self, buf: &mut [u8]) {
+                    buf[..Self::SIZE].copy_from_slice(&self.to_le_bytes());
+                }
+
+                fn read_bytes(buf: &[u8]) -> Self {
+                    let mut bytes = [0; core::mem::size_of::<$t>()];
+                    bytes.copy_from_slice(&buf[..Self::SIZE]);
+                    <$t>::from_le_bytes(bytes)
+                }
+            }
+        )*
+    };
+}
+
+impl_fixed_bytes!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+mod private {
+    pub trait Sealed {}
+}
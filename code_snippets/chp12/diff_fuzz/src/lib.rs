@@ -1,22 +1,59 @@
 /*!
 Buggy version of the [`scapegoat` crate](https://docs.rs/scapegoat/latest/scapegoat/), for differential fuzzing blog post.
+
+### Features
+
+* `arbitrary`: implements [`arbitrary::Arbitrary`](https://docs.rs/arbitrary/latest/arbitrary/trait.Arbitrary.html) for [`SgMap`] and [`SgSet`], for property testing. Generation stops at capacity.
+* `alloc`: enables APIs that heap-allocate their results, like [`SgMap::into_keys_values`] and [`SgMap::to_dot`]. Still `no_std`, requires a global allocator.
+
+### Fuzzing
+
+The `fuzz/` directory contains a [`cargo-fuzz`](https://github.com/rust-fuzz/cargo-fuzz) target, `sg_map`, that drives [`SgMap`] with arbitrary sequences of operations and asserts equivalence against `std`'s [`BTreeMap`](https://doc.rust-lang.org/std/collections/struct.BTreeMap.html), checking tree invariants after every operation.
+Requires a nightly toolchain:
+
+```text
+cargo install cargo-fuzz
+cargo +nightly fuzz run sg_map
+```
+
+The seed corpus (`fuzz/corpus/sg_map/`) includes an input reproducing this crate's two-child node removal bug, so the target should fail within the first run.
+
+### Benchmarks
+
+The `heapless_cmp` bench compares [`SgMap`] insert, get, and iteration time, as well as stack footprint, against [`heapless::FnvIndexMap`](https://docs.rs/heapless/latest/heapless/index_map/type.FnvIndexMap.html) at equal capacities.
+Results are printed as CSV, for tracking regressions across commits:
+
+```text
+cargo bench --bench heapless_cmp > bench.csv
+```
 */
 
 #![forbid(unsafe_code)]
 #![cfg_attr(not(any(test, fuzzing)), no_std)]
 #![cfg_attr(not(any(test, fuzzing)), deny(missing_docs))]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 mod tree;
-pub use crate::tree::SgError;
+pub use crate::tree::{ArenaStats, SgError};
 
 mod map;
 pub use crate::map::SgMap;
 
-/// [`SgMap`][crate::map::SgMap]'s iterator return types and [`Entry`](crate::map_types::Entry) enum.
+/// [`SgMap`][crate::map::SgMap]'s iterator return types, [`Entry`](crate::map_types::Entry) enum, and [`MapBuilder`](crate::map_types::MapBuilder).
 pub mod map_types;
 
 mod set;
 pub use crate::set::SgSet;
 
-/// [`SgSet`][crate::set::SgSet]'s iterator return types.
+/// [`SgSet`][crate::set::SgSet]'s iterator return types and [`SetBuilder`](crate::set_types::SetBuilder).
 pub mod set_types;
+
+mod capacity;
+pub use crate::capacity::OrderedCapacity;
+
+mod fixed_bytes;
+pub use crate::fixed_bytes::FixedBytes;
+
+mod checksum;
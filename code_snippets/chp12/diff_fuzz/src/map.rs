@@ -1,22 +1,43 @@
 use core::borrow::Borrow;
 use core::fmt::{self, Debug};
-use core::iter::FromIterator;
-use core::ops::Index;
+use core::hash::Hasher;
+use core::iter::{FromIterator, Sum};
+use core::ops::{AddAssign, Index, RangeBounds};
 
+use crate::checksum::Crc32;
+use crate::fixed_bytes::MAX_FIXED_BYTES_SIZE;
 use crate::map_types::{
-    Entry, IntoIter, IntoKeys, IntoValues, Iter, IterMut, Keys, OccupiedEntry, VacantEntry, Values,
-    ValuesMut,
+    Entry, EntryRef, IntoIter, IntoKeys, IntoValues, Iter, IterMut, Keys, MapBuilder,
+    OccupiedEntry, RangeMut, VacantEntry, VacantEntryRef, Values, ValuesMut,
 };
-use crate::tree::{SgError, SgTree};
+use crate::tree::{ArenaStats, SgError, SgTree};
+use crate::{FixedBytes, SgSet};
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+// Size of `serialize_into`'s pair count prefix, in bytes
+const SERIALIZED_LEN_SIZE: usize = core::mem::size_of::<u32>();
 
 /// Safe, fallible, embedded-friendly ordered map.
 ///
 /// ### Fallible APIs
 ///
 /// * [`try_insert`][crate::map::SgMap::try_insert]
+/// * [`try_insert_unique`][crate::map::SgMap::try_insert_unique]
 /// * [`try_append`][crate::map::SgMap::try_append]
+/// * [`try_add`][crate::map::SgMap::try_add]
 /// * [`try_extend`][crate::map::SgMap::try_extend]
+/// * [`try_extend_updates`][crate::map::SgMap::try_extend_updates]
+/// * [`try_extend_unique`][crate::map::SgMap::try_extend_unique]
+/// * [`try_increment`][crate::map::SgMap::try_increment]
+/// * [`try_clone_filtered`][crate::map::SgMap::try_clone_filtered]
+/// * [`try_clone_into`][crate::map::SgMap::try_clone_into]
+/// * [`try_extend_from_slice`][crate::map::SgMap::try_extend_from_slice]
 /// * [`try_from_iter`][crate::map::SgMap::try_from_iter]
+/// * [`collect_checked`][crate::map::SgMap::collect_checked]
+/// * [`serialize_into`][crate::map::SgMap::serialize_into]
+/// * [`deserialize_from`][crate::map::SgMap::deserialize_from]
 ///
 /// [`TryFrom`](https://doc.rust-lang.org/stable/std/convert/trait.TryFrom.html) isn't implemented because it would collide with the blanket implementation.
 /// See [this open GitHub issue](https://github.com/rust-lang/rust/issues/50133#issuecomment-64690839) from 2018,
@@ -32,6 +53,26 @@ pub struct SgMap<K: Ord + Default, V: Default, const N: usize> {
 }
 
 impl<K: Ord + Default, V: Default, const N: usize> SgMap<K, V, N> {
+    /// An empty `SgMap`, usable in `const` and `static` initializers (unlike [`new`][crate::map::SgMap::new]).
+    /// Exceeding the maximum capacity (`u16::MAX`) is a compile-time error, instead of a runtime panic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Mutex;
+    /// use buggy_scapegoat::SgMap;
+    ///
+    /// static REGISTERS: Mutex<SgMap<u8, u32, 16>> = Mutex::new(SgMap::EMPTY);
+    ///
+    /// REGISTERS.lock().unwrap().insert(0x10, 0xdead_beef);
+    /// assert_eq!(REGISTERS.lock().unwrap().get(&0x10), Some(&0xdead_beef));
+    /// ```
+    ///
+    /// ### Note
+    ///
+    /// `new` can't be a `const fn`: it builds storage via `Default`, and trait methods aren't `const` on stable.
+    pub const EMPTY: Self = SgMap { bst: SgTree::EMPTY };
+
     /// Makes a new, empty `SgMap`.
     ///
     /// # Examples
@@ -47,6 +88,26 @@ impl<K: Ord + Default, V: Default, const N: usize> SgMap<K, V, N> {
         SgMap { bst: SgTree::new() }
     }
 
+    /// Makes a new, empty [`MapBuilder`] for adding pairs one at a time, with rejected pairs returned once full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgMap;
+    ///
+    /// let mut builder = SgMap::<_, _, 2>::builder();
+    /// for (k, v) in [(3, 'c'), (1, 'a'), (2, 'b')] {
+    ///     if let Err(rejected) = builder.push(k, v) {
+    ///         assert_eq!(rejected, (2, 'b'));
+    ///     }
+    /// }
+    ///
+    /// assert!(builder.finish().into_iter().eq([(1, 'a'), (3, 'c')]));
+    /// ```
+    pub fn builder() -> MapBuilder<K, V, N> {
+        MapBuilder { map: SgMap::new() }
+    }
+
     /// The [original buggy_scapegoat tree paper's](https://people.csail.mit.edu/rivest/pubs/GR93.pdf) alpha, `a`, can be chosen in the range `0.5 <= a < 1.0`.
     /// `a` tunes how "aggressively" the data structure self-balances.
     /// It controls the trade-off between total rebuild time and maximum height guarantees.
@@ -97,6 +158,139 @@ impl<K: Ord + Default, V: Default, const N: usize> SgMap<K, V, N> {
         self.bst.rebal_param()
     }
 
+    /// Suggest a rebalance parameter, alpha, for the workload observed so far, as `(alpha_numerator, alpha_denominator)`.
+    /// This is a heuristic: frequent rebuilds (e.g. sorted inserts) trend toward `1.0`, rare rebuilds toward `0.5`.
+    /// See [the corresponding setter method][SgMap::set_rebal_param] for the trade-off.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgMap;
+    ///
+    /// let mut map: SgMap<usize, usize, 64> = SgMap::new();
+    ///
+    /// // Sorted order, insert-heavy
+    /// map.extend((0..64).map(|i| (i, i)));
+    ///
+    /// let (num, denom) = map.suggest_alpha();
+    /// assert!(num / denom > 2.0 / 3.0);
+    /// ```
+    #[doc(alias = "alpha")]
+    pub fn suggest_alpha(&self) -> (f32, f32) {
+        self.bst.suggest_alpha()
+    }
+
+    /// Apply the rebalance parameter from [`suggest_alpha`][SgMap::suggest_alpha], for subsequent operations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgMap;
+    ///
+    /// let mut map: SgMap<usize, usize, 64> = SgMap::new();
+    /// map.extend((0..64).map(|i| (i, i)));
+    ///
+    /// let suggested = map.suggest_alpha();
+    /// map.auto_tune_alpha();
+    /// assert_eq!(map.rebal_param(), suggested);
+    /// ```
+    #[doc(alias = "alpha")]
+    pub fn auto_tune_alpha(&mut self) {
+        self.bst.auto_tune_alpha()
+    }
+
+    /// Reset the rebalance count, which [`suggest_alpha`][SgMap::suggest_alpha] uses to infer workload, to zero.
+    /// Contents are unchanged. Useful when starting a new workload phase, so prior operations don't skew suggestions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgMap;
+    ///
+    /// let mut map: SgMap<usize, usize, 64> = SgMap::new();
+    ///
+    /// // Sorted order, insert-heavy
+    /// map.extend((0..64).map(|i| (i, i)));
+    /// assert!(map.suggest_alpha() > (50.0, 100.0));
+    ///
+    /// // No rebuilds observed since reset
+    /// map.reset_rebal_cnt();
+    /// assert_eq!(map.suggest_alpha(), (50.0, 100.0));
+    /// assert_eq!(map.len(), 64);
+    /// ```
+    pub fn reset_rebal_cnt(&mut self) {
+        self.bst.reset_rebal_cnt()
+    }
+
+    /// Set a strict maximum tree height, or `None` for no limit (default), to bound worst-case lookup cost.
+    /// Any insert that would exceed the limit triggers an immediate full rebuild, trading insert cost for the bound.
+    ///
+    /// A tree of height `h` holds at most `2^h - 1` elements.
+    /// Fallible inserts return [`SgError::MaximumHeightExceeded`] if a new element wouldn't fit within the limit,
+    /// infallible inserts panic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::{SgMap, SgError};
+    ///
+    /// let mut map: SgMap<usize, usize, 10> = SgMap::new();
+    /// map.set_max_height(Some(3));
+    ///
+    /// // Sorted order, worst case
+    /// for i in 0..7 {
+    ///     map.insert(i, i);
+    /// }
+    ///
+    /// assert_eq!(map.try_insert(7, 7), Err(SgError::MaximumHeightExceeded));
+    /// ```
+    pub fn set_max_height(&mut self, max: Option<usize>) {
+        self.bst.set_max_height(max)
+    }
+
+    /// Get the maximum tree height, if any.
+    /// See [the corresponding setter method][SgMap::set_max_height] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgMap;
+    ///
+    /// let mut map: SgMap<usize, usize, 10> = SgMap::new();
+    /// assert_eq!(map.max_height(), None);
+    ///
+    /// map.set_max_height(Some(4));
+    /// assert_eq!(map.max_height(), Some(4));
+    /// ```
+    pub fn max_height(&self) -> Option<usize> {
+        self.bst.max_height()
+    }
+
+    /// Set a callback invoked whenever the map rebuilds a subtree, e.g. to log rebalancing on-device.
+    /// The callback receives the internal index of the rebuilt subtree's root (the scapegoat) and the subtree's size.
+    /// Zero-cost when unset (default).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgMap;
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    ///
+    /// static REBUILD_CNT: AtomicUsize = AtomicUsize::new(0);
+    ///
+    /// let mut map = SgMap::<usize, usize, 10>::new();
+    /// map.set_rebalance_callback(|_, _| {
+    ///     REBUILD_CNT.fetch_add(1, Ordering::Relaxed);
+    /// });
+    ///
+    /// // Sorted insertion, worst case
+    /// map.extend((0..10).map(|i| (i, i)));
+    /// assert!(REBUILD_CNT.load(Ordering::Relaxed) > 0);
+    /// ```
+    pub fn set_rebalance_callback(&mut self, cb: fn(scapegoat_idx: usize, subtree_size: usize)) {
+        self.bst.set_rebalance_callback(cb)
+    }
+
     /// Total capacity, e.g. maximum number of map pairs.
     ///
     /// # Examples
@@ -112,6 +306,22 @@ impl<K: Ord + Default, V: Default, const N: usize> SgMap<K, V, N> {
         self.bst.capacity()
     }
 
+    /// Total capacity, e.g. maximum number of map pairs, usable in const contexts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgMap;
+    ///
+    /// const CAPACITY: usize = SgMap::<usize, &str, 10>::max_capacity_const();
+    /// let buf = [0_u8; CAPACITY];
+    ///
+    /// assert!(buf.len() == 10);
+    /// ```
+    pub const fn max_capacity_const() -> usize {
+        N
+    }
+
     /// Gets an iterator over the keys of the map, in sorted order.
     ///
     /// # Examples
@@ -152,6 +362,29 @@ impl<K: Ord + Default, V: Default, const N: usize> SgMap<K, V, N> {
         }
     }
 
+    /// Returns `true` if the map's keys are exactly the elements of the given set, ignoring values.
+    /// Compares in sorted order, without allocation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::{SgMap, SgSet};
+    ///
+    /// let mut map = SgMap::<_, _, 10>::new();
+    /// map.insert(2, "b");
+    /// map.insert(1, "a");
+    ///
+    /// let mut set = SgSet::<_, 5>::new();
+    /// set.insert(1);
+    /// assert!(!map.keys_eq(&set));
+    ///
+    /// set.insert(2);
+    /// assert!(map.keys_eq(&set));
+    /// ```
+    pub fn keys_eq<const M: usize>(&self, other: &SgSet<K, M>) -> bool {
+        (self.len() == other.len()) && self.keys().eq(other.iter())
+    }
+
     /// Gets an iterator over the values of the map, in order by key.
     ///
     /// # Examples
@@ -192,6 +425,59 @@ impl<K: Ord + Default, V: Default, const N: usize> SgMap<K, V, N> {
         }
     }
 
+    /// Consumes the map, returning its keys and values as two index-aligned vectors, sorted by key.
+    /// Handy for columnar (struct-of-arrays) processing.
+    /// Requires the `alloc` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgMap;
+    ///
+    /// let mut a = SgMap::<_, _, 10>::new();
+    /// a.insert(2, "goodbye");
+    /// a.insert(1, "hello");
+    ///
+    /// let (keys, values) = a.into_keys_values();
+    /// assert_eq!(keys, [1, 2]);
+    /// assert_eq!(values, ["hello", "goodbye"]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn into_keys_values(self) -> (Vec<K>, Vec<V>) {
+        self.into_iter().unzip()
+    }
+
+    /// Attempt to convert a full map into an array of key-value pairs, sorted by key.
+    /// If the map isn't full (e.g. `len() != N`), it's returned unchanged as the error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<_, _, 3>::new();
+    /// map.insert(3, "c");
+    /// map.insert(1, "a");
+    ///
+    /// // Not full
+    /// let mut map = map.try_into_array().unwrap_err();
+    /// assert_eq!(map.len(), 2);
+    ///
+    /// // Full
+    /// map.insert(2, "b");
+    /// assert_eq!(map.try_into_array().ok(), Some([(1, "a"), (2, "b"), (3, "c")]));
+    /// ```
+    pub fn try_into_array(self) -> Result<[(K, V); N], Self> {
+        if self.len() != N {
+            return Err(self);
+        }
+
+        let mut iter = self.into_iter();
+        Ok(core::array::from_fn(|_| {
+            iter.next().expect("Full map must yield N items")
+        }))
+    }
+
     /// Gets a mutable iterator over the values of the map, in order by key.
     ///
     /// # Examples
@@ -217,8 +503,75 @@ impl<K: Ord + Default, V: Default, const N: usize> SgMap<K, V, N> {
         }
     }
 
+    /// Folds every value of the map into an accumulator, in order by key.
+    /// Convenience for `values().fold(init, f)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgMap;
+    ///
+    /// let mut a = SgMap::<_, _, 10>::new();
+    /// a.insert(1, 20);
+    /// a.insert(2, 30);
+    /// a.insert(3, 10);
+    ///
+    /// assert_eq!(a.fold_values(0, |max, &v| max.max(v)), 30);
+    /// ```
+    pub fn fold_values<B, F: FnMut(B, &V) -> B>(&self, init: B, f: F) -> B {
+        self.values().fold(init, f)
+    }
+
+    /// Sums every value of the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgMap;
+    ///
+    /// let mut a = SgMap::<_, _, 10>::new();
+    /// a.insert("temp_0", 20);
+    /// a.insert("temp_1", 30);
+    ///
+    /// assert_eq!(a.sum_values(), 50);
+    /// ```
+    pub fn sum_values(&self) -> V
+    where
+        V: Copy + Sum,
+    {
+        self.values().copied().sum()
+    }
+
+    /// Calls a fallible closure on each pair of the map, in order by key, stopping at the first error.
+    /// Convenience for `iter().try_for_each(...)`, with key and value passed separately.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgMap;
+    ///
+    /// let mut a = SgMap::<_, _, 10>::new();
+    /// a.insert(1, "a");
+    /// a.insert(2, "");
+    /// a.insert(3, "c");
+    ///
+    /// let res = a.try_for_each(|k, v| match v.is_empty() {
+    ///     true => Err(*k),
+    ///     false => Ok(()),
+    /// });
+    /// assert_eq!(res, Err(2));
+    /// ```
+    pub fn try_for_each<E, F: FnMut(&K, &V) -> Result<(), E>>(&self, mut f: F) -> Result<(), E> {
+        self.iter().try_for_each(|(k, v)| f(k, v))
+    }
+
     /// Moves all elements from `other` into `self`, leaving `other` empty.
     ///
+    /// # Panics
+    ///
+    /// Panics if the union of `self` and `other` exceeds the capacity `N`, leaving both unchanged.
+    /// Use [`try_append`][crate::map::SgMap::try_append] to handle this case.
+    ///
     /// # Examples
     ///
     /// ```
@@ -304,6 +657,31 @@ impl<K: Ord + Default, V: Default, const N: usize> SgMap<K, V, N> {
         self.bst.try_append(&mut other.bst)
     }
 
+    /// Attempts to merge `other` into `self`, taking ownership of it.
+    /// On key collision, `other`'s pair overwrites the existing pair.
+    /// The fallible counterpart of `a += b`.
+    ///
+    /// Returns `Err`, with `self` unchanged, if the merged pairs wouldn't fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::{SgMap, SgError};
+    ///
+    /// let mut a = SgMap::<_, _, 4>::from_iter([(1, "a"), (2, "b")]);
+    /// let b = SgMap::<_, _, 4>::from_iter([(2, "c"), (3, "d")]);
+    /// let c = SgMap::<_, _, 4>::from_iter([(4, "e"), (5, "f")]);
+    ///
+    /// assert!(a.try_add(b).is_ok());
+    /// assert!(a.iter().eq([(&1, &"a"), (&2, &"c"), (&3, &"d")]));
+    ///
+    /// assert_eq!(a.try_add(c), Err(SgError::StackCapacityExceeded));
+    /// assert_eq!(a.len(), 3);
+    /// ```
+    pub fn try_add(&mut self, mut other: SgMap<K, V, N>) -> Result<(), SgError> {
+        self.try_append(&mut other)
+    }
+
     /// Insert a key-value pair into the map.
     /// If the map did not have this key present, `None` is returned.
     /// If the map did have this key present, the value is updated, the old value is returned,
@@ -378,6 +756,102 @@ impl<K: Ord + Default, V: Default, const N: usize> SgMap<K, V, N> {
         self.bst.try_insert(key, val)
     }
 
+    /// Attempt to insert a key-value pair into the map, rejecting keys that are already present.
+    /// Unlike [`try_insert`][crate::map::SgMap::try_insert], an existing value is never overwritten.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::{SgMap, SgError};
+    ///
+    /// let mut map = SgMap::<_, _, 2>::new();
+    ///
+    /// // New key
+    /// assert_eq!(map.try_insert_unique("port", 8080), Ok(()));
+    ///
+    /// // Duplicate key, existing value kept
+    /// assert_eq!(map.try_insert_unique("port", 9090), Err(SgError::DuplicateKey));
+    /// assert_eq!(map["port"], 8080);
+    ///
+    /// // Full
+    /// assert_eq!(map.try_insert_unique("host", 1), Ok(()));
+    /// assert_eq!(map.try_insert_unique("user", 2), Err(SgError::StackCapacityExceeded));
+    /// ```
+    pub fn try_insert_unique(&mut self, key: K, val: V) -> Result<(), SgError>
+    where
+        K: Ord,
+    {
+        self.bst.try_insert_unique(key, val)
+    }
+
+    /// Insert a key-value pair into the map, using the map as a bounded cache.
+    /// If there's no room for a new key (the map is full, or inserting would exceed the
+    /// [maximum height][SgMap::set_max_height]), `evict` is called to choose a victim key, which is removed to make room.
+    ///
+    /// Returns `Ok` if the pair was stored, with `Some` evicted pair only if an eviction made room for it.
+    /// So an update of a present key, which never evicts, returns `Ok(None)` (the old value is dropped, the stored key
+    /// kept - use [`insert`][crate::map::SgMap::insert] to get the old value back).
+    ///
+    /// Returns `Err` with the passed-in pair, unchanged, if it was rejected (nothing is inserted or removed):
+    /// `evict` didn't name a present key, or the map already exceeds its maximum height so an eviction wouldn't help.
+    /// A rejected pair is handed back rather than dropped, hence a `Result` instead of a plain `Option<(K, V)>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgMap;
+    ///
+    /// let mut cache = SgMap::<_, _, 2>::new();
+    /// let evict_min = |c: &SgMap<_, _, 2>| c.first_key().copied();
+    ///
+    /// assert_eq!(cache.insert_with_eviction(1, "a", evict_min), Ok(None));
+    /// assert_eq!(cache.insert_with_eviction(2, "b", evict_min), Ok(None));
+    ///
+    /// // Full, new key
+    /// assert_eq!(cache.insert_with_eviction(3, "c", evict_min), Ok(Some((1, "a"))));
+    ///
+    /// // Full, present key updated without eviction
+    /// assert_eq!(cache.insert_with_eviction(3, "C", evict_min), Ok(None));
+    ///
+    /// // Full, no victim
+    /// assert_eq!(cache.insert_with_eviction(4, "d", |_| None), Err((4, "d")));
+    /// assert!(cache.into_iter().eq([(2, "b"), (3, "C")]));
+    /// ```
+    pub fn insert_with_eviction<F>(
+        &mut self,
+        key: K,
+        val: V,
+        mut evict: F,
+    ) -> Result<Option<(K, V)>, (K, V)>
+    where
+        K: Ord,
+        F: FnMut(&SgMap<K, V, N>) -> Option<K>,
+    {
+        if let Some(curr_val) = self.get_mut(&key) {
+            *curr_val = val;
+            return Ok(None);
+        }
+
+        // Growth checked, so neither insert below can panic in maximum height mode
+        if self.bst.check_growth(1).is_ok() {
+            self.bst.insert(key, val);
+            return Ok(None);
+        }
+
+        // A one-for-one swap only helps if the current size fits, e.g. not if the maximum height was lowered below it
+        if self.bst.check_growth(0).is_err() {
+            return Err((key, val));
+        }
+
+        match evict(self).and_then(|victim| self.remove_entry(&victim)) {
+            Some(evicted) => {
+                self.bst.insert(key, val);
+                Ok(Some(evicted))
+            }
+            None => Err((key, val)),
+        }
+    }
+
     /// Attempt to extend a collection with the contents of an iterator.
     ///
     /// # Examples
@@ -407,24 +881,125 @@ impl<K: Ord + Default, V: Default, const N: usize> SgMap<K, V, N> {
         self.bst.try_extend(iter)
     }
 
-    /// Attempt conversion from an iterator.
-    /// Will fail if iterator length exceeds `u16::MAX`.
+    /// Attempt to extend a collection with the contents of an iterator whose items may overwrite existing keys.
+    /// Unlike [`try_extend`][crate::map::SgMap::try_extend], only keys not already in the map count against remaining capacity.
     ///
     /// # Examples
     ///
     /// ```
     /// use buggy_scapegoat::{SgMap, SgError};
     ///
-    /// const CAPACITY_1: usize = 1_000;
-    /// let vec: Vec<(usize, usize)> = (0..CAPACITY_1).map(|n|(n, n)).collect();
-    /// assert!(SgMap::<usize, usize, CAPACITY_1>::try_from_iter(vec.into_iter()).is_ok());
+    /// let mut map = SgMap::<_, _, 3>::from_iter([(1, "a"), (2, "b"), (3, "c")]);
+    /// assert!(map.is_full());
     ///
-    /// const CAPACITY_2: usize = (u16::MAX as usize) + 1;
-    /// let vec: Vec<(usize, usize)> = (0..CAPACITY_2).map(|n|(n, n)).collect();
-    /// assert_eq!(
-    ///     SgMap::<usize, usize, CAPACITY_2>::try_from_iter(vec.into_iter()),
-    ///     Err(SgError::MaximumCapacityExceeded)
-    /// );
+    /// // Only existing keys, fits
+    /// assert_eq!(map.try_extend([(1, "x"), (3, "z")].into_iter()), Err(SgError::StackCapacityExceeded));
+    /// assert!(map.try_extend_updates([(1, "x"), (3, "z")]).is_ok());
+    /// assert_eq!(map.get(&1), Some(&"x"));
+    /// assert_eq!(map.get(&3), Some(&"z"));
+    ///
+    /// // New key, too big
+    /// assert_eq!(map.try_extend_updates([(2, "y"), (4, "d")]), Err(SgError::StackCapacityExceeded));
+    /// assert_eq!(map.get(&2), Some(&"b"));
+    /// assert!(!map.contains_key(&4));
+    /// ```
+    ///
+    /// ### Note
+    ///
+    /// The iterator must be `Clone`: a first pass buffers up to the remaining capacity's worth of distinct new keys
+    /// on the stack to check for fit, a second pass inserts. The map is left unmodified on error.
+    pub fn try_extend_updates<I>(&mut self, iter: I) -> Result<(), SgError>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        I::IntoIter: Clone,
+    {
+        self.bst.try_extend_updates(iter)
+    }
+
+    /// Attempt to extend a collection with the contents of an iterator, rejecting any key already in the map
+    /// or repeated within the iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::{SgMap, SgError};
+    ///
+    /// let mut map = SgMap::<_, _, 4>::from_iter([("host", 1)]);
+    ///
+    /// // Key already present, map left unchanged
+    /// assert_eq!(map.try_extend_unique([("port", 2), ("host", 3)]), Err(SgError::DuplicateKey));
+    /// assert_eq!(map.len(), 1);
+    ///
+    /// // Key repeated within batch, map left unchanged
+    /// assert_eq!(map.try_extend_unique([("port", 2), ("port", 3)]), Err(SgError::DuplicateKey));
+    /// assert_eq!(map.len(), 1);
+    ///
+    /// // Distinct new keys
+    /// assert!(map.try_extend_unique([("port", 2), ("user", 3)]).is_ok());
+    /// assert!(map.iter().eq([(&"host", &1), (&"port", &2), (&"user", &3)]));
+    /// ```
+    ///
+    /// ### Note
+    ///
+    /// The iterator must be `Clone`: a first pass buffers up to the remaining capacity's worth of new keys
+    /// on the stack to check for duplicates and fit, a second pass inserts. The map is left unmodified on error.
+    pub fn try_extend_unique<I>(&mut self, iter: I) -> Result<(), SgError>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        I::IntoIter: Clone,
+    {
+        self.bst.try_extend_unique(iter)
+    }
+
+    /// Attempt to extend a collection with copies of a slice's key-value pairs.
+    /// Only keys not already in the map count against remaining capacity.
+    /// If a key appears more than once, the last value wins.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::{SgMap, SgError};
+    ///
+    /// let mut map = SgMap::<_, _, 3>::from_iter([(1, 'a')]);
+    ///
+    /// // Fits
+    /// assert!(map.try_extend_from_slice(&[(2, 'b'), (1, 'x')]).is_ok());
+    /// assert!(map.iter().eq([(&1, &'x'), (&2, &'b')]));
+    ///
+    /// // Duplicate keys, only one new key
+    /// assert!(map.try_extend_from_slice(&[(3, 'c'), (3, 'z')]).is_ok());
+    /// assert_eq!(map.get(&3), Some(&'z'));
+    ///
+    /// // Too big, map left unchanged
+    /// assert_eq!(map.try_extend_from_slice(&[(1, 'y'), (4, 'd')]), Err(SgError::StackCapacityExceeded));
+    /// assert!(map.iter().eq([(&1, &'x'), (&2, &'b'), (&3, &'z')]));
+    /// ```
+    pub fn try_extend_from_slice(&mut self, slice: &[(K, V)]) -> Result<(), SgError>
+    where
+        K: Copy,
+        V: Copy,
+    {
+        self.bst.try_extend_updates(slice.iter().copied())
+    }
+
+    /// Attempt conversion from an iterator.
+    /// Will fail if iterator length exceeds `u16::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::{SgMap, SgError};
+    ///
+    /// const CAPACITY_1: usize = 1_000;
+    /// let vec: Vec<(usize, usize)> = (0..CAPACITY_1).map(|n|(n, n)).collect();
+    /// assert!(SgMap::<usize, usize, CAPACITY_1>::try_from_iter(vec.into_iter()).is_ok());
+    ///
+    /// const CAPACITY_2: usize = (u16::MAX as usize) + 1;
+    /// let vec: Vec<(usize, usize)> = (0..CAPACITY_2).map(|n|(n, n)).collect();
+    /// assert_eq!(
+    ///     SgMap::<usize, usize, CAPACITY_2>::try_from_iter(vec.into_iter()),
+    ///     Err(SgError::MaximumCapacityExceeded)
+    /// );
     /// ```
     ///
     /// ### Note
@@ -439,6 +1014,228 @@ impl<K: Ord + Default, V: Default, const N: usize> SgMap<K, V, N> {
         }
     }
 
+    /// Attempt conversion from an iterator of any length.
+    /// Will fail if the number of unique keys exceeds the map's capacity.
+    ///
+    /// This is the recommended fallible builder: the [`FromIterator`] implementation (e.g. [`collect`][Iterator::collect])
+    /// panics on overflow, and [`try_from_iter`][crate::map::SgMap::try_from_iter] requires an [`ExactSizeIterator`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::{SgMap, SgError};
+    ///
+    /// let map = SgMap::<_, _, 3>::collect_checked((0..3).map(|n| (n, n)));
+    /// assert!(map.is_ok());
+    ///
+    /// let map = SgMap::<_, _, 3>::collect_checked((0..4).map(|n| (n, n)));
+    /// assert_eq!(map, Err(SgError::StackCapacityExceeded));
+    /// ```
+    pub fn collect_checked<I: IntoIterator<Item = (K, V)>>(iter: I) -> Result<Self, SgError> {
+        Ok(SgMap {
+            bst: SgTree::collect_checked(iter)?,
+        })
+    }
+
+    /// Serialize the map into `buf`, without `alloc`, e.g. for persisting to flash.
+    /// Returns the number of bytes written.
+    ///
+    /// The format is a little-endian `u32` pair count followed by each pair's key and value bytes, in key order.
+    /// See [`FixedBytes`] for supported key and value types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::{SgMap, SgError};
+    ///
+    /// let map: SgMap<u8, u16, 10> = [(1, 0x0101), (2, 0x0202)].into_iter().collect();
+    ///
+    /// let mut buf = [0; 16];
+    /// assert_eq!(map.serialize_into(&mut buf), Ok(10));
+    /// assert_eq!(buf[..10], [2, 0, 0, 0, 1, 1, 1, 2, 2, 2]);
+    ///
+    /// let mut small_buf = [0; 8];
+    /// assert_eq!(map.serialize_into(&mut small_buf), Err(SgError::BufferTooSmall));
+    /// ```
+    pub fn serialize_into(&self, buf: &mut [u8]) -> Result<usize, SgError>
+    where
+        K: FixedBytes,
+        V: FixedBytes,
+    {
+        let pair_size = K::SIZE + V::SIZE;
+        let total_size = SERIALIZED_LEN_SIZE + (self.len() * pair_size);
+        if buf.len() < total_size {
+            return Err(SgError::BufferTooSmall);
+        }
+
+        let pair_cnt = u32::try_from(self.len()).map_err(|_| SgError::MaximumCapacityExceeded)?;
+        pair_cnt.write_bytes(buf);
+
+        for ((k, v), chunk) in self
+            .iter()
+            .zip(buf[SERIALIZED_LEN_SIZE..total_size].chunks_exact_mut(pair_size))
+        {
+            k.write_bytes(chunk);
+            v.write_bytes(&mut chunk[K::SIZE..]);
+        }
+
+        Ok(total_size)
+    }
+
+    /// Deserialize a map from `buf`, the format written by [`serialize_into`][crate::map::SgMap::serialize_into].
+    /// Trailing bytes past the encoded pairs are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::{SgMap, SgError};
+    ///
+    /// let buf = [2, 0, 0, 0, 1, 1, 1, 2, 2, 2];
+    /// let map = SgMap::<u8, u16, 10>::deserialize_from(&buf).unwrap();
+    /// assert!(map.into_iter().eq([(1, 0x0101), (2, 0x0202)]));
+    ///
+    /// // Truncated
+    /// assert_eq!(
+    ///     SgMap::<u8, u16, 10>::deserialize_from(&buf[..9]),
+    ///     Err(SgError::BufferTooSmall)
+    /// );
+    ///
+    /// // Too many pairs for capacity
+    /// assert_eq!(
+    ///     SgMap::<u8, u16, 1>::deserialize_from(&buf),
+    ///     Err(SgError::StackCapacityExceeded)
+    /// );
+    /// ```
+    pub fn deserialize_from(buf: &[u8]) -> Result<Self, SgError>
+    where
+        K: FixedBytes,
+        V: FixedBytes,
+    {
+        if buf.len() < SERIALIZED_LEN_SIZE {
+            return Err(SgError::BufferTooSmall);
+        }
+
+        let pair_cnt = u32::read_bytes(buf) as usize;
+        if pair_cnt > N {
+            return Err(SgError::StackCapacityExceeded);
+        }
+
+        let pair_size = K::SIZE + V::SIZE;
+        let total_size = SERIALIZED_LEN_SIZE + (pair_cnt * pair_size);
+        if buf.len() < total_size {
+            return Err(SgError::BufferTooSmall);
+        }
+
+        let mut map = Self::new();
+        for chunk in buf[SERIALIZED_LEN_SIZE..total_size].chunks_exact(pair_size) {
+            map.try_insert(K::read_bytes(chunk), V::read_bytes(&chunk[K::SIZE..]))?;
+        }
+
+        Ok(map)
+    }
+
+    /// Returns a CRC-32 checksum of the map's contents, e.g. to validate persisted data on load.
+    /// Maps with equal contents have equal checksums, regardless of insertion order or capacity.
+    ///
+    /// The checksum covers the same little-endian bytes [`serialize_into`][crate::map::SgMap::serialize_into]
+    /// writes, so it equals the CRC-32 of a serialized buffer.
+    /// [`FixedBytes`] is only implemented for fixed-width integers, so the checksum is the same on every target.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgMap;
+    ///
+    /// let a: SgMap<u8, u16, 10> = [(1, 0x0101), (2, 0x0202)].into_iter().collect();
+    /// let mut b: SgMap<u8, u16, 10> = [(2, 0x0202), (1, 0x0101)].into_iter().collect();
+    /// assert_eq!(a.content_checksum(), b.content_checksum());
+    ///
+    /// b.insert(2, 0x0303);
+    /// assert_ne!(a.content_checksum(), b.content_checksum());
+    /// ```
+    pub fn content_checksum(&self) -> u32
+    where
+        K: FixedBytes,
+        V: FixedBytes,
+    {
+        let mut crc = Crc32::new();
+        let mut scratch = [0; MAX_FIXED_BYTES_SIZE];
+
+        (self.len() as u32).write_bytes(&mut scratch);
+        crc.write(&scratch[..SERIALIZED_LEN_SIZE]);
+
+        for (k, v) in self.iter() {
+            k.write_bytes(&mut scratch);
+            crc.write(&scratch[..K::SIZE]);
+            v.write_bytes(&mut scratch);
+            crc.write(&scratch[..V::SIZE]);
+        }
+
+        crc.checksum()
+    }
+
+    /// Conversion from an iterator, inserting until the map is full.
+    /// Returns the map and the number of items dropped for lack of capacity.
+    ///
+    /// Items with a key already in the map update it, even when full.
+    /// Which items are dropped depends on iteration order, *not* key order.
+    /// E.g. a full map keeps the first `N` unique keys seen, not the `N` smallest.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgMap;
+    ///
+    /// let (map, dropped_cnt) = SgMap::<_, _, 3>::from_iter_truncated([(5, 'e'), (1, 'a'), (4, 'd'), (2, 'b')]);
+    /// assert_eq!(dropped_cnt, 1);
+    /// assert!(map.into_iter().eq([(1, 'a'), (4, 'd'), (5, 'e')]));
+    /// ```
+    pub fn from_iter_truncated<I: IntoIterator<Item = (K, V)>>(iter: I) -> (Self, usize) {
+        let mut sgm = SgMap::new();
+        let dropped_cnt = iter
+            .into_iter()
+            .map(|(k, v)| sgm.try_insert(k, v))
+            .filter(Result::is_err)
+            .count();
+
+        (sgm, dropped_cnt)
+    }
+
+    /// Extend the map with the contents of an iterator, stopping at the first pair that can't be inserted
+    /// (the map is full, or the [maximum height][SgMap::set_max_height] is reached).
+    /// The rest of the iterator is left unconsumed, so an unbounded iterator is fine.
+    /// Returns the number of new keys inserted, updates to existing keys are applied but not counted.
+    /// Unlike [`Extend`], never panics for lack of capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<_, _, 3>::new();
+    /// map.insert(1, 'a');
+    ///
+    /// let mut iter = [(1, 'z'), (2, 'b'), (3, 'c'), (4, 'd'), (5, 'e')].into_iter();
+    /// assert_eq!(map.saturating_extend(iter.by_ref()), 2);
+    /// assert!(map.is_full());
+    /// assert!(map.into_iter().eq([(1, 'z'), (2, 'b'), (3, 'c')]));
+    ///
+    /// // Stopped at the first rejected pair
+    /// assert!(iter.eq([(5, 'e')]));
+    /// ```
+    pub fn saturating_extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) -> usize {
+        let mut inserted_cnt = 0;
+        for (k, v) in iter {
+            match self.try_insert(k, v) {
+                Ok(None) => inserted_cnt += 1,
+                Ok(Some(_)) => {}
+                Err(_) => break,
+            }
+        }
+
+        inserted_cnt
+    }
+
     /// Gets an iterator over the entries of the map, sorted by key.
     ///
     /// # Examples
@@ -488,6 +1285,95 @@ impl<K: Ord + Default, V: Default, const N: usize> SgMap<K, V, N> {
         IterMut::new(self)
     }
 
+    /// Gets a mutable iterator over a sub-range of entries in the map, sorted by key.
+    ///
+    /// The key may be any borrowed form of the map’s key type, but the ordering
+    /// on the borrowed form must match the ordering on the key type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgMap;
+    ///
+    /// let mut map: SgMap<usize, usize, 10> = (0..8).map(|i| (i, i)).collect();
+    ///
+    /// // Add 10 to every value with a key in [2, 5)
+    /// for (_, value) in map.range_mut(2..5) {
+    ///     *value += 10;
+    /// }
+    ///
+    /// assert!(map.range_mut(2..5).map(|(k, v)| (*k, *v)).eq([(2, 12), (3, 13), (4, 14)]));
+    /// assert!(map.iter().filter(|(k, _)| !(2..5).contains(*k)).all(|(k, v)| k == v));
+    /// ```
+    pub fn range_mut<Q, R>(&mut self, range: R) -> RangeMut<'_, K, V, N>
+    where
+        K: Borrow<Q>,
+        R: RangeBounds<Q>,
+        Q: Ord + ?Sized,
+    {
+        RangeMut::new(self, range)
+    }
+
+    /// Splits the map into a pair of mutable iterators, sorted by key: one over entries with keys less than `key`,
+    /// the other over entries with keys greater than or equal to `key`.
+    /// The halves are disjoint, so they can be used simultaneously (e.g. for divide-and-conquer processing).
+    ///
+    /// The key may be any borrowed form of the map’s key type, but the ordering
+    /// on the borrowed form must match the ordering on the key type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgMap;
+    ///
+    /// let mut map: SgMap<usize, usize, 10> = (0..8).map(|i| (i, i)).collect();
+    ///
+    /// let (lo, hi) = map.split_at_key_mut(&5);
+    /// assert_eq!((lo.len(), hi.len()), (5, 3));
+    ///
+    /// // Interleave halves, stopping at the shorter: double below 5, add 10 at or above
+    /// for (lo_entry, hi_entry) in lo.zip(hi) {
+    ///     *lo_entry.1 *= 2;
+    ///     *hi_entry.1 += 10;
+    /// }
+    ///
+    /// assert!(map.values().copied().eq([0, 2, 4, 3, 4, 15, 16, 17]));
+    /// ```
+    pub fn split_at_key_mut<Q>(&mut self, key: &Q) -> (IterMut<'_, K, V, N>, IterMut<'_, K, V, N>)
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        IterMut::new_split(self, key)
+    }
+
+    /// Gets an iterator over a sub-range of entries in the map, sorted by key.
+    /// A non-panicking alternative to indexing when a window of entries is needed:
+    /// missing keys, empty ranges, and inverted ranges (e.g. `5..2`) all yield nothing.
+    ///
+    /// The key may be any borrowed form of the map’s key type, but the ordering
+    /// on the borrowed form must match the ordering on the key type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgMap;
+    ///
+    /// let map: SgMap<usize, char, 10> = [(1, 'a'), (3, 'c'), (5, 'e'), (7, 'g')].into_iter().collect();
+    ///
+    /// assert!(map.get_range(2..6).eq([(&3, &'c'), (&5, &'e')]));
+    /// assert!(map.get_range(5..).eq([(&5, &'e'), (&7, &'g')]));
+    /// assert_eq!(map.get_range(8..).count(), 0);
+    /// ```
+    pub fn get_range<Q, R>(&self, range: R) -> impl Iterator<Item = (&K, &V)>
+    where
+        K: Borrow<Q>,
+        R: RangeBounds<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.bst.get_range(range)
+    }
+
     /// Removes a key from the map, returning the stored key and value if the key
     /// was previously in the map.
     ///
@@ -535,6 +1421,122 @@ impl<K: Ord + Default, V: Default, const N: usize> SgMap<K, V, N> {
         self.bst.retain(|k, v| f(k, v));
     }
 
+    /// Retains only the elements specified by the predicate, like [`retain`][crate::map::SgMap::retain],
+    /// but returns the removed pairs as a new map instead of dropping them.
+    /// The removed pairs always fit, since they came from a map of the same capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgMap;
+    ///
+    /// let mut map: SgMap<i32, i32, 10> = (0..8).map(|x| (x, x*10)).collect();
+    /// // Keep only the elements with even-numbered keys, collect the rest.
+    /// let odds = map.retain_collect(|&k, _| k % 2 == 0);
+    /// assert!(map.into_iter().eq(vec![(0, 0), (2, 20), (4, 40), (6, 60)]));
+    /// assert!(odds.into_iter().eq(vec![(1, 10), (3, 30), (5, 50), (7, 70)]));
+    /// ```
+    pub fn retain_collect<F>(&mut self, mut f: F) -> SgMap<K, V, N>
+    where
+        K: Ord,
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        SgMap {
+            bst: self.bst.retain_collect(|k, v| f(k, v)),
+        }
+    }
+
+    /// Retains only the elements whose key is specified by the predicate.
+    /// A key-only convenience for [`retain`][crate::map::SgMap::retain], for parity with [`SgSet::retain`][crate::set::SgSet::retain].
+    ///
+    /// In other words, remove all pairs `(k, v)` such that `f(&k)` returns `false`.
+    /// The elements are visited in ascending key order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgMap;
+    ///
+    /// let mut map: SgMap<i32, i32, 10> = (0..8).map(|x| (x, x*10)).collect();
+    /// // Keep only the elements with even-numbered keys.
+    /// map.retain_keys(|&k| k % 2 == 0);
+    /// assert!(map.into_iter().eq(vec![(0, 0), (2, 20), (4, 40), (6, 60)]));
+    /// ```
+    pub fn retain_keys<F>(&mut self, mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&K) -> bool,
+    {
+        self.retain(|k, _| f(k));
+    }
+
+    /// Returns a new map containing clones of the pairs specified by the predicate, leaving `self` unchanged.
+    /// In other words, the non-mutating counterpart of [`retain`][crate::map::SgMap::retain].
+    ///
+    /// The filtered pairs always fit, since capacity `N` is shared.
+    /// The `Result` is kept for symmetry with other fallible APIs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgMap;
+    ///
+    /// let map: SgMap<i32, i32, 10> = (0..8).map(|x| (x, x*10)).collect();
+    /// let evens = map.try_clone_filtered(|&k, _| k % 2 == 0).unwrap();
+    ///
+    /// assert!(evens.into_iter().eq(vec![(0, 0), (2, 20), (4, 40), (6, 60)]));
+    /// assert_eq!(map.len(), 8);
+    /// ```
+    pub fn try_clone_filtered<F>(&self, mut pred: F) -> Result<Self, SgError>
+    where
+        K: Clone + Ord,
+        V: Clone,
+        F: FnMut(&K, &V) -> bool,
+    {
+        Self::collect_checked(
+            self.iter()
+                .filter(|(k, v)| pred(k, v))
+                .map(|(k, v)| (k.clone(), v.clone())),
+        )
+    }
+
+    /// Returns a clone of the map with a different capacity, `M`, leaving `self` unchanged.
+    /// The rebalance parameter and maximum height are carried over.
+    ///
+    /// Returns `Err` if the map has more than `M` pairs, e.g. `M` is smaller than `N` and the map is too full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::{SgMap, SgError};
+    ///
+    /// let map: SgMap<i32, i32, 10> = (0..4).map(|x| (x, x*10)).collect();
+    ///
+    /// let smaller = map.try_clone_into::<4>().unwrap();
+    /// assert_eq!(smaller.capacity(), 4);
+    /// assert!(smaller.iter().eq(map.iter()));
+    ///
+    /// assert_eq!(map.try_clone_into::<3>(), Err(SgError::StackCapacityExceeded));
+    /// ```
+    pub fn try_clone_into<const M: usize>(&self) -> Result<SgMap<K, V, M>, SgError>
+    where
+        K: Clone + Ord,
+        V: Clone,
+    {
+        if self.len() > M {
+            return Err(SgError::StackCapacityExceeded);
+        }
+
+        let mut map =
+            SgMap::<K, V, M>::collect_checked(self.iter().map(|(k, v)| (k.clone(), v.clone())))?;
+
+        let (alpha_num, alpha_denom) = self.rebal_param();
+        map.set_rebal_param(alpha_num, alpha_denom)?;
+        map.set_max_height(self.max_height());
+
+        Ok(map)
+    }
+
     /// Splits the collection into two at the given key. Returns everything after the given key,
     /// including the key.
     ///
@@ -596,6 +1598,39 @@ impl<K: Ord + Default, V: Default, const N: usize> SgMap<K, V, N> {
         self.bst.remove(key)
     }
 
+    /// Removes a key from the map, returning the value at the key if the key
+    /// was previously in the map, and the next-larger key in the map, if any.
+    /// Supports forward scanning with removal, without holding an iterator.
+    ///
+    /// The key may be any borrowed form of the map's key type, but the ordering
+    /// on the borrowed form *must* match the ordering on the key type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgMap;
+    ///
+    /// let mut map: SgMap<usize, usize, 10> = (0..10).map(|i| (i, i)).collect();
+    ///
+    /// // Remove every odd value, scanning forward
+    /// let mut opt_key = map.first_key().copied();
+    /// while let Some(key) = opt_key {
+    ///     opt_key = match map[&key] % 2 {
+    ///         1 => map.remove_and_next(&key).1,
+    ///         _ => map.get_range(key + 1..).next().map(|(k, _)| *k),
+    ///     };
+    /// }
+    ///
+    /// assert!(map.into_keys().eq([0, 2, 4, 6, 8]));
+    /// ```
+    pub fn remove_and_next<Q>(&mut self, key: &Q) -> (Option<V>, Option<K>)
+    where
+        K: Borrow<Q> + Ord + Clone,
+        Q: Ord + ?Sized,
+    {
+        self.bst.remove_and_next(key)
+    }
+
     /// Returns the key-value pair corresponding to the supplied key.
     ///
     /// The supplied key may be any borrowed form of the map's key type, but the ordering
@@ -668,6 +1703,7 @@ impl<K: Ord + Default, V: Default, const N: usize> SgMap<K, V, N> {
     }
 
     /// Clears the map, removing all elements.
+    /// The [maximum height][SgMap::set_max_height], if any, is kept.
     ///
     /// # Examples
     ///
@@ -683,6 +1719,132 @@ impl<K: Ord + Default, V: Default, const N: usize> SgMap<K, V, N> {
         self.bst.clear()
     }
 
+    /// Clears the map, removing all elements and resetting internal rebalance statistics.
+    /// Unlike [`clear`][crate::map::SgMap::clear], which preserves statistics to track performance over the map's lifetime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgMap;
+    ///
+    /// let mut a = SgMap::<_, _, 10>::new();
+    /// a.insert(1, "a");
+    /// a.clear_stats();
+    /// assert!(a.is_empty());
+    /// ```
+    pub fn clear_stats(&mut self) {
+        self.bst.clear_stats()
+    }
+
+    /// Compacts the map's backing storage, reclaiming slots left empty by removals.
+    /// Useful after heavy insert/remove churn, contents are unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgMap;
+    ///
+    /// let mut map: SgMap<usize, usize, 10> = (0..10).map(|i| (i, i)).collect();
+    /// map.retain(|k, _| k % 3 == 0);
+    ///
+    /// map.defragment();
+    /// assert!(map.into_iter().eq([(0, 0), (3, 3), (6, 6), (9, 9)]));
+    /// ```
+    pub fn defragment(&mut self) {
+        self.bst.defragment()
+    }
+
+    /// Get occupancy statistics for the map's backing storage: live pairs, holes left by removals, and backing length.
+    /// Useful for quantifying fragmentation, e.g. to decide when to [`defragment`][SgMap::defragment].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::{ArenaStats, SgMap};
+    ///
+    /// let mut map: SgMap<usize, usize, 10> = (0..4).map(|i| (i, i)).collect();
+    /// map.remove(&3);
+    ///
+    /// assert_eq!(
+    ///     map.arena_stats(),
+    ///     ArenaStats {
+    ///         live: 3,
+    ///         holes: 1,
+    ///         backing_len: 4,
+    ///     }
+    /// );
+    /// ```
+    pub fn arena_stats(&self) -> ArenaStats {
+        self.bst.arena_stats()
+    }
+
+    /// Render the structure of the underlying tree in [Graphviz DOT](https://graphviz.org/doc/info/lang.html) format,
+    /// e.g. to observe rebalancing. Nodes are labeled by keys, edges by left (`L`) or right (`R`) child.
+    /// Requires the `alloc` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgMap;
+    ///
+    /// let map = SgMap::<_, _, 10>::from_iter([(2, "b"), (1, "a"), (3, "c")]);
+    /// let dot = map.to_dot();
+    ///
+    /// assert!(dot.starts_with("digraph SgTree {"));
+    /// assert!(dot.contains("[label=\"1\"]"));
+    /// assert_eq!(dot.matches(" -> ").count(), 2);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_dot(&self) -> String
+    where
+        K: Debug,
+    {
+        self.bst.to_dot()
+    }
+
+    /// Rebuilds the map's tree into optimal balance, e.g. before a read-heavy phase when a high alpha was set.
+    /// Counts towards the map's rebalance statistics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<_, _, 10>::new();
+    /// map.set_rebal_param(0.9, 1.0).unwrap();
+    /// map.extend((0..10).map(|i| (i, i)));
+    ///
+    /// map.rebalance();
+    /// assert!(map.into_iter().eq((0..10).map(|i| (i, i))));
+    /// ```
+    pub fn rebalance(&mut self) {
+        self.bst.rebalance()
+    }
+
+    /// Compacts the map's backing storage and rebuilds its tree into optimal balance, e.g. after heavy churn.
+    /// The rebalance parameter set via [`set_rebal_param`][SgMap::set_rebal_param] is preserved.
+    /// Counts towards the map's rebalance statistics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<_, _, 10>::new();
+    /// map.set_rebal_param(0.9, 1.0).unwrap();
+    /// map.extend((0..10).map(|i| (i, i)));
+    /// (0..5).for_each(|_| {
+    ///     map.pop_first();
+    /// });
+    ///
+    /// map.rebuild_balanced();
+    /// assert_eq!(map.rebal_param(), (0.9, 1.0));
+    /// assert!(map.into_iter().eq((5..10).map(|i| (i, i))));
+    /// ```
+    pub fn rebuild_balanced(&mut self) {
+        self.bst.rebuild_balanced()
+    }
+
     /// Returns `true` if the map contains a value for the specified key.
     ///
     /// The key may be any borrowed form of the map's key type, but the ordering
@@ -705,6 +1867,33 @@ impl<K: Ord + Default, V: Default, const N: usize> SgMap<K, V, N> {
         self.bst.contains_key(key)
     }
 
+    /// Returns the 0-based position of a key in the map's sorted order, or `None` if the key is absent.
+    ///
+    /// The key may be any borrowed form of the map's key type, but the ordering
+    /// on the borrowed form *must* match the ordering on the key type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgMap;
+    ///
+    /// let map = SgMap::<_, _, 10>::from_iter([(30, "c"), (10, "a"), (20, "b")]);
+    /// assert_eq!(map.rank(&10), Some(0));
+    /// assert_eq!(map.rank(&30), Some(2));
+    /// assert_eq!(map.rank(&15), None);
+    /// ```
+    ///
+    /// ### Note
+    ///
+    /// Rank is computed by an `O(n)` in-order traversal.
+    pub fn rank<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.bst.rank(key)
+    }
+
     /// Returns `true` if the map contains no elements.
     ///
     /// # Examples
@@ -759,6 +1948,31 @@ impl<K: Ord + Default, V: Default, const N: usize> SgMap<K, V, N> {
         self.bst.first_key_value()
     }
 
+    /// Returns the first key-value pair in the map, with a mutable reference to the value.
+    /// The key in this pair is the minimum key in the map.
+    /// Lighter-weight than [`first_entry`][crate::map::SgMap::first_entry] for in-place value updates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<_, _, 10>::new();
+    /// assert_eq!(map.first_key_value_mut(), None);
+    /// map.insert(1, "b");
+    /// map.insert(2, "a");
+    /// if let Some((_, v)) = map.first_key_value_mut() {
+    ///     *v = "first";
+    /// }
+    /// assert_eq!(map.first_key_value(), Some((&1, &"first")));
+    /// ```
+    pub fn first_key_value_mut(&mut self) -> Option<(&K, &mut V)>
+    where
+        K: Ord,
+    {
+        self.bst.first_key_value_mut()
+    }
+
     /// Returns a reference to the first/minium key in the map, if any.
     ///
     /// # Examples
@@ -779,6 +1993,27 @@ impl<K: Ord + Default, V: Default, const N: usize> SgMap<K, V, N> {
         self.bst.first_key()
     }
 
+    /// Returns a copy of the first/minimum key in the map, if any.
+    /// A by-value alternative to [`first_key`][SgMap::first_key] for `Copy` types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<_, _, 10>::new();
+    /// assert_eq!(map.first_key_copied(), None);
+    /// map.insert(1, "b");
+    /// map.insert(2, "a");
+    /// assert_eq!(map.first_key_copied(), Some(1));
+    /// ```
+    pub fn first_key_copied(&self) -> Option<K>
+    where
+        K: Ord + Copy,
+    {
+        self.first_key().copied()
+    }
+
     /// Removes and returns the first element in the map.
     /// The key of this element is the minimum key that was in the map.
     ///
@@ -815,16 +2050,61 @@ impl<K: Ord + Default, V: Default, const N: usize> SgMap<K, V, N> {
     /// let mut map = SgMap::<_, _, 10>::new();
     /// map.insert(1, "b");
     /// map.insert(2, "a");
-    /// assert_eq!(map.last_key_value(), Some((&2, &"a")));
+    /// assert_eq!(map.last_key_value(), Some((&2, &"a")));
+    /// ```
+    pub fn last_key_value(&self) -> Option<(&K, &V)>
+    where
+        K: Ord,
+    {
+        self.bst.last_key_value()
+    }
+
+    /// Returns the last key-value pair in the map, with a mutable reference to the value.
+    /// The key in this pair is the maximum key in the map.
+    /// Lighter-weight than [`last_entry`][crate::map::SgMap::last_entry] for in-place value updates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<_, _, 10>::new();
+    /// assert_eq!(map.last_key_value_mut(), None);
+    /// map.insert(1, "b");
+    /// map.insert(2, "a");
+    /// if let Some((_, v)) = map.last_key_value_mut() {
+    ///     *v = "last";
+    /// }
+    /// assert_eq!(map.last_key_value(), Some((&2, &"last")));
+    /// ```
+    pub fn last_key_value_mut(&mut self) -> Option<(&K, &mut V)>
+    where
+        K: Ord,
+    {
+        self.bst.last_key_value_mut()
+    }
+
+    /// Returns a reference to the last/maximum key in the map, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<_, _, 10>::new();
+    /// map.insert(1, "b");
+    /// map.insert(2, "a");
+    /// assert_eq!(map.last_key(), Some(&2));
     /// ```
-    pub fn last_key_value(&self) -> Option<(&K, &V)>
+    pub fn last_key(&self) -> Option<&K>
     where
         K: Ord,
     {
-        self.bst.last_key_value()
+        self.bst.last_key()
     }
 
-    /// Returns a reference to the last/maximum key in the map, if any.
+    /// Returns a copy of the last/maximum key in the map, if any.
+    /// A by-value alternative to [`last_key`][SgMap::last_key] for `Copy` types.
     ///
     /// # Examples
     ///
@@ -832,15 +2112,16 @@ impl<K: Ord + Default, V: Default, const N: usize> SgMap<K, V, N> {
     /// use buggy_scapegoat::SgMap;
     ///
     /// let mut map = SgMap::<_, _, 10>::new();
+    /// assert_eq!(map.last_key_copied(), None);
     /// map.insert(1, "b");
     /// map.insert(2, "a");
-    /// assert_eq!(map.last_key(), Some(&2));
+    /// assert_eq!(map.last_key_copied(), Some(2));
     /// ```
-    pub fn last_key(&self) -> Option<&K>
+    pub fn last_key_copied(&self) -> Option<K>
     where
-        K: Ord,
+        K: Ord + Copy,
     {
-        self.bst.last_key()
+        self.last_key().copied()
     }
 
     /// Removes and returns the last element in the map.
@@ -884,6 +2165,13 @@ impl<K: Ord + Default, V: Default, const N: usize> SgMap<K, V, N> {
         self.bst.len()
     }
 
+    /// Panic if an internal tree invariant doesn't hold.
+    /// Only available when testing or fuzzing (`cfg(fuzzing)` is set by `cargo fuzz`).
+    #[cfg(any(test, fuzzing))]
+    pub fn assert_invariants(&self) {
+        self.bst.assert_invariants();
+    }
+
     /// Gets the given key's corresponding entry in the map for in-place manipulation.
     ///
     /// # Examples
@@ -916,6 +2204,78 @@ impl<K: Ord + Default, V: Default, const N: usize> SgMap<K, V, N> {
         }
     }
 
+    /// Gets the given key's corresponding entry in the map for in-place manipulation, looked up by a borrowed form of the key.
+    /// Unlike [`entry`][crate::map::SgMap::entry], an owned key is only constructed (via `From<&Q>`) if a vacant entry is inserted into.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgMap;
+    ///
+    /// let mut count = SgMap::<String, usize, 10>::new();
+    ///
+    /// // count the number of occurrences of letters in the vec, allocating only for new letters
+    /// for x in ["a", "b", "a", "c", "a", "b"] {
+    ///     *count.entry_ref(x).or_insert(0) += 1;
+    /// }
+    ///
+    /// assert_eq!(count["a"], 3);
+    /// ```
+    pub fn entry_ref<'a, Q>(&'a mut self, key: &'a Q) -> EntryRef<'a, K, Q, V, N>
+    where
+        K: Borrow<Q> + From<&'a Q>,
+        Q: Ord + ?Sized,
+    {
+        use crate::tree::node::NodeGetHelper;
+        use crate::tree::Idx;
+
+        let ngh: NodeGetHelper<Idx> = self.bst.priv_get(None, key);
+        match ngh.node_idx() {
+            Some(node_idx) => EntryRef::Occupied(OccupiedEntry {
+                node_idx,
+                table: self,
+            }),
+            None => EntryRef::Vacant(VacantEntryRef { key, table: self }),
+        }
+    }
+
+    /// Adds `by` to the value for `key`, or inserts `by` if the key is absent.
+    /// Returns a mutable reference to the updated value, or an error if the key is absent and can't be inserted
+    /// (the map is full, or inserting would exceed the [maximum height][SgMap::set_max_height]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::{SgMap, SgError};
+    ///
+    /// let mut count = SgMap::<char, usize, 2>::new();
+    ///
+    /// for c in "abba".chars() {
+    ///     count.try_increment(c, 1).unwrap();
+    /// }
+    ///
+    /// assert_eq!(count[&'a'], 2);
+    /// assert_eq!(count.try_increment('b', 3), Ok(&mut 5));
+    /// assert_eq!(count.try_increment('c', 1), Err(SgError::StackCapacityExceeded));
+    /// ```
+    pub fn try_increment(&mut self, key: K, by: V) -> Result<&mut V, SgError>
+    where
+        V: AddAssign + Copy,
+    {
+        let growth = self.bst.check_growth(1);
+        match self.entry(key) {
+            Entry::Occupied(occupied) => {
+                let val = occupied.into_mut();
+                *val += by;
+                Ok(val)
+            }
+            Entry::Vacant(vacant) => {
+                growth?;
+                Ok(vacant.insert(by))
+            }
+        }
+    }
+
     /// Returns the first entry in the map for in-place manipulation.
     /// The key of this entry is the minimum key in the map.
     ///
@@ -992,6 +2352,44 @@ where
     }
 }
 
+// Arbitrary, for property testing. Stops generating at capacity.
+#[cfg(feature = "arbitrary")]
+impl<'a, K, V, const N: usize> arbitrary::Arbitrary<'a> for SgMap<K, V, N>
+where
+    K: Ord + Default + arbitrary::Arbitrary<'a>,
+    V: Default + arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut map = SgMap::new();
+        for pair in u.arbitrary_iter::<(K, V)>()? {
+            let (k, v) = pair?;
+            if map.try_insert(k, v).is_err() {
+                break;
+            }
+        }
+
+        Ok(map)
+    }
+}
+
+// Display
+impl<K: Default, V: Default, const N: usize> fmt::Display for SgMap<K, V, N>
+where
+    K: Ord + fmt::Display,
+    V: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{")?;
+        for (i, (k, v)) in self.bst.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}: {}", k, v)?;
+        }
+        write!(f, "}}")
+    }
+}
+
 // From array.
 impl<K: Default, V: Default, const N: usize> From<[(K, V); N]> for SgMap<K, V, N>
 where
@@ -1036,7 +2434,9 @@ where
     }
 }
 
-// Construct from iterator.
+/// Construct from iterator.
+///
+/// Panics if capacity is exceeded, use [`collect_checked`][crate::map::SgMap::collect_checked] for a fallible alternative.
 impl<K: Default, V: Default, const N: usize> FromIterator<(K, V)> for SgMap<K, V, N>
 where
     K: Ord,
@@ -1069,6 +2469,19 @@ where
     }
 }
 
+/// Merge another map into this one, e.g. `a += b`.
+/// On key collision, the right-hand map's pair overwrites the existing pair.
+///
+/// Panics if capacity is exceeded, use [`try_add`][crate::map::SgMap::try_add] for a fallible alternative.
+impl<K: Default, V: Default, const N: usize> AddAssign<SgMap<K, V, N>> for SgMap<K, V, N>
+where
+    K: Ord,
+{
+    fn add_assign(&mut self, mut rhs: SgMap<K, V, N>) {
+        self.append(&mut rhs);
+    }
+}
+
 // General Iterators ---------------------------------------------------------------------------------------------------
 
 // Reference iterator
@@ -1090,3 +2503,808 @@ impl<K: Ord + Default, V: Default, const N: usize> IntoIterator for SgMap<K, V,
         IntoIter::new(self)
     }
 }
+
+// Test ----------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::{SgError, SgMap};
+    use crate::checksum::Crc32;
+    use crate::{ArenaStats, SgSet};
+    use core::hash::Hasher;
+
+    #[test]
+    fn test_display() {
+        let mut map = SgMap::<usize, &str, 10>::new();
+        assert_eq!(map.to_string(), "{}");
+
+        map.insert(2, "b");
+        assert_eq!(map.to_string(), "{2: b}");
+
+        map.insert(3, "c");
+        map.insert(1, "a");
+        assert_eq!(map.to_string(), "{1: a, 2: b, 3: c}");
+    }
+
+    #[test]
+    fn test_max_capacity_const() {
+        const CAPACITY: usize = SgMap::<u8, u8, 12>::max_capacity_const();
+        const _: () = assert!(CAPACITY == 12);
+
+        let map = SgMap::<u8, u8, CAPACITY>::new();
+        let buf = [0_u8; SgMap::<u8, u8, CAPACITY>::max_capacity_const()];
+        assert_eq!(buf.len(), map.capacity());
+    }
+
+    #[test]
+    fn test_keys_eq() {
+        let map: SgMap<usize, &str, 10> = [(1, "a"), (3, "c"), (5, "e")].into_iter().collect();
+
+        let matching: SgSet<usize, 3> = SgSet::from([5, 3, 1]);
+        let missing: SgSet<usize, 10> = [1, 3].into_iter().collect();
+        let extra: SgSet<usize, 10> = [1, 3, 5, 7].into_iter().collect();
+        let different: SgSet<usize, 10> = [1, 3, 4].into_iter().collect();
+
+        assert!(map.keys_eq(&matching));
+        assert!(!map.keys_eq(&missing));
+        assert!(!map.keys_eq(&extra));
+        assert!(!map.keys_eq(&different));
+
+        assert!(SgMap::<usize, &str, 10>::new().keys_eq(&SgSet::<usize, 1>::new()));
+    }
+
+    #[test]
+    fn test_from_iter_truncated() {
+        const CAPACITY: usize = 10;
+
+        // Descending insertion order, so the largest keys are kept
+        let (map, dropped_cnt) =
+            SgMap::<usize, usize, CAPACITY>::from_iter_truncated((0..15).rev().map(|i| (i, i)));
+        assert_eq!(map.len(), CAPACITY);
+        assert_eq!(dropped_cnt, 5);
+        assert!(map.keys().copied().eq(5..15));
+
+        // Updates to existing keys aren't dropped
+        let (map, dropped_cnt) = SgMap::<usize, usize, CAPACITY>::from_iter_truncated(
+            (0..15).map(|i| (i, i)).chain([(0, 100)]),
+        );
+        assert_eq!(map.len(), CAPACITY);
+        assert_eq!(dropped_cnt, 5);
+        assert_eq!(map.get(&0), Some(&100));
+    }
+
+    #[test]
+    fn test_collect_checked() {
+        const CAPACITY: usize = 10;
+
+        // Fits, duplicate keys don't consume capacity
+        let map = SgMap::<usize, usize, CAPACITY>::collect_checked(
+            (0..CAPACITY).chain(0..CAPACITY).map(|i| (i, i)),
+        )
+        .unwrap();
+        assert_eq!(map.len(), CAPACITY);
+
+        // Overflows
+        assert_eq!(
+            SgMap::<usize, usize, CAPACITY>::collect_checked((0..=CAPACITY).map(|i| (i, i))),
+            Err(SgError::StackCapacityExceeded)
+        );
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_get_range_empty() {
+        let map: SgMap<usize, usize, 10> = (0..10).step_by(2).map(|i| (i, i)).collect();
+
+        assert_eq!(map.get_range(3..3).count(), 0);
+        assert_eq!(map.get_range(3..4).count(), 0);
+        assert_eq!(map.get_range(7..2).count(), 0);
+        assert_eq!(map.get_range(20..).count(), 0);
+        assert_eq!(SgMap::<usize, usize, 10>::new().get_range(..).count(), 0);
+
+        assert!(map.get_range(2..=6).map(|(k, _)| *k).eq([2, 4, 6]));
+        assert!(map.get_range(..).map(|(k, _)| *k).eq([0, 2, 4, 6, 8]));
+    }
+
+    #[test]
+    fn test_remove_and_next() {
+        let mut map: SgMap<usize, &str, 10> = [(1, "a"), (3, "c"), (5, "e"), (7, "g"), (9, "i")]
+            .into_iter()
+            .collect();
+
+        // Middle key, successor returned
+        assert_eq!(map.remove_and_next(&5), (Some("e"), Some(7)));
+        assert!(map.keys().copied().eq([1, 3, 7, 9]));
+
+        // Absent key, successor still returned
+        assert_eq!(map.remove_and_next(&4), (None, Some(7)));
+        assert_eq!(map.len(), 4);
+
+        // Max key, no successor
+        assert_eq!(map.remove_and_next(&9), (Some("i"), None));
+        assert_eq!(map.remove_and_next(&1), (Some("a"), Some(3)));
+        assert!(map.keys().copied().eq([3, 7]));
+    }
+
+    #[test]
+    fn test_fold_sum_values() {
+        let map: SgMap<usize, i32, 10> = [(1, 4), (2, -7), (3, 12), (4, 3)].into_iter().collect();
+
+        assert_eq!(map.sum_values(), 12);
+        assert_eq!(map.fold_values(i32::MIN, |max, &v| max.max(v)), 12);
+        assert_eq!(map.fold_values(0, |cnt, &v| cnt + usize::from(v < 0)), 1);
+
+        let empty = SgMap::<usize, i32, 10>::new();
+        assert_eq!(empty.sum_values(), 0);
+        assert_eq!(empty.fold_values(i32::MIN, |max, &v| max.max(v)), i32::MIN);
+    }
+
+    #[test]
+    fn test_retain_keys() {
+        let mut map: SgMap<usize, String, 10> = (0..10).map(|i| (i, i.to_string())).collect();
+        let mut visited = Vec::new();
+
+        map.retain_keys(|&k| {
+            visited.push(k);
+            k % 2 == 0
+        });
+
+        assert!(visited.into_iter().eq(0..10));
+        assert!(map.keys().copied().eq([0, 2, 4, 6, 8]));
+        assert!(map.values().all(|v| v.parse::<usize>().unwrap() % 2 == 0));
+    }
+
+    #[test]
+    fn test_into_keys_values_rev() {
+        let map: SgMap<usize, char, 10> = [(3, 'c'), (1, 'a'), (4, 'd'), (2, 'b')]
+            .into_iter()
+            .collect();
+
+        assert!(map.clone().into_values().rev().eq(['d', 'c', 'b', 'a']));
+        assert!(map.clone().into_keys().rev().eq([4, 3, 2, 1]));
+
+        let mut keys = map.into_keys();
+        assert_eq!(keys.next_back(), Some(4));
+        assert_eq!(keys.next(), Some(1));
+        assert_eq!(keys.len(), 2);
+    }
+
+    #[test]
+    fn test_first_last_key_value_mut() {
+        let mut map: SgMap<usize, usize, 10> = (1..=5).map(|i| (i, i * 10)).collect();
+
+        if let Some((k, v)) = map.first_key_value_mut() {
+            assert_eq!(*k, 1);
+            *v += 1;
+        }
+
+        if let Some((k, v)) = map.last_key_value_mut() {
+            assert_eq!(*k, 5);
+            *v += 1;
+        }
+
+        assert_eq!(map.first_key_value(), Some((&1, &11)));
+        assert_eq!(map.last_key_value(), Some((&5, &51)));
+
+        // Cached indexes track removals
+        map.pop_first();
+        *map.first_key_value_mut().unwrap().1 = 0;
+        assert_eq!(map.first_key_value(), Some((&2, &0)));
+
+        map.clear();
+        assert!(map.first_key_value_mut().is_none());
+        assert!(map.last_key_value_mut().is_none());
+    }
+
+    #[test]
+    fn test_try_increment() {
+        const ALPHABET: [char; 4] = ['a', 'c', 'g', 't'];
+        let mut counts = SgMap::<char, u32, { ALPHABET.len() }>::new();
+
+        for c in "gattacacatgcagta".chars() {
+            assert!(counts.try_increment(c, 1).is_ok());
+        }
+
+        assert!(counts.keys().eq(ALPHABET.iter()));
+        assert!(counts.values().eq([6, 3, 3, 4].iter()));
+        assert_eq!(counts.sum_values(), 16);
+
+        *counts.try_increment('t', 10).unwrap() -= 1;
+        assert_eq!(counts[&'t'], 13);
+
+        // Full, new keys rejected
+        assert_eq!(
+            counts.try_increment('u', 1),
+            Err(SgError::StackCapacityExceeded)
+        );
+        assert_eq!(counts.len(), ALPHABET.len());
+
+        // Height-bounded, new keys rejected without panic
+        let mut counts = SgMap::<char, u32, { ALPHABET.len() }>::new();
+        counts.set_max_height(Some(2));
+        for c in "act".chars() {
+            assert!(counts.try_increment(c, 1).is_ok());
+        }
+        assert_eq!(
+            counts.try_increment('g', 1),
+            Err(SgError::MaximumHeightExceeded)
+        );
+        assert_eq!(*counts.try_increment('a', 1).unwrap(), 2);
+        assert_eq!(counts.len(), 3);
+    }
+
+    #[test]
+    fn test_serialize_round_trip() {
+        const CAPACITY: usize = 16;
+        let map: SgMap<i16, u64, CAPACITY> = (-8..8).map(|k| (k, (k as u64) << 40)).collect();
+
+        let mut buf = [0xff; 4 + (CAPACITY * (2 + 8)) + 3];
+        let written = map.serialize_into(&mut buf).unwrap();
+        assert_eq!(written, buf.len() - 3);
+
+        let decoded = SgMap::<i16, u64, CAPACITY>::deserialize_from(&buf).unwrap();
+        assert_eq!(decoded, map);
+        decoded.assert_invariants();
+
+        let empty = SgMap::<i16, u64, CAPACITY>::new();
+        assert_eq!(empty.serialize_into(&mut buf), Ok(4));
+        assert!(SgMap::<i16, u64, CAPACITY>::deserialize_from(&buf)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_serialize_buffer_too_small() {
+        let map: SgMap<u32, u32, 4> = (0..4).map(|k| (k, k)).collect();
+        let mut buf = [0; 4 + (4 * 8)];
+
+        assert_eq!(
+            map.serialize_into(&mut buf[..35]),
+            Err(SgError::BufferTooSmall)
+        );
+        assert_eq!(map.serialize_into(&mut buf), Ok(36));
+
+        assert_eq!(
+            SgMap::<u32, u32, 4>::deserialize_from(&buf[..3]),
+            Err(SgError::BufferTooSmall)
+        );
+        assert_eq!(
+            SgMap::<u32, u32, 4>::deserialize_from(&buf[..35]),
+            Err(SgError::BufferTooSmall)
+        );
+        assert_eq!(
+            SgMap::<u32, u32, 3>::deserialize_from(&buf),
+            Err(SgError::StackCapacityExceeded)
+        );
+    }
+
+    #[test]
+    fn test_saturating_extend() {
+        const CAPACITY: usize = 16;
+        let mut map = SgMap::<usize, usize, CAPACITY>::new();
+
+        assert_eq!(map.saturating_extend((0..4).map(|i| (i, i))), 4);
+
+        // Updates aren't counted
+        assert_eq!(
+            map.saturating_extend((0..(CAPACITY * 2)).map(|i| (i, i * 2))),
+            CAPACITY - 4
+        );
+        assert!(map.is_full());
+        assert!(map
+            .iter()
+            .map(|(k, v)| (*k, *v))
+            .eq((0..CAPACITY).map(|i| (i, i * 2))));
+        map.assert_invariants();
+
+        // Full, stops at the first new key
+        assert_eq!(map.saturating_extend([(0, 1), (CAPACITY, 0), (1, 1)]), 0);
+        assert_eq!(map.len(), CAPACITY);
+        assert_eq!(map[&0], 1);
+        assert_eq!(map[&1], 2);
+
+        // Unbounded iterator terminates
+        assert_eq!(map.saturating_extend((CAPACITY..).map(|i| (i, i))), 0);
+    }
+
+    #[test]
+    fn test_try_clone_filtered() {
+        const CAPACITY: usize = 32;
+        let map: SgMap<usize, [u8; 4], CAPACITY> = (0..CAPACITY)
+            .map(|i| (i, (i as u32).to_le_bytes()))
+            .collect();
+
+        let evens = map.try_clone_filtered(|k, _| k % 2 == 0).unwrap();
+        assert_eq!(evens.len(), CAPACITY / 2);
+        assert!(evens.iter().eq(map.iter().filter(|(k, _)| *k % 2 == 0)));
+        evens.assert_invariants();
+
+        // Original untouched
+        assert_eq!(map.len(), CAPACITY);
+
+        // Edge cases
+        assert!(map.try_clone_filtered(|_, _| false).unwrap().is_empty());
+        assert_eq!(map.try_clone_filtered(|_, _| true), Ok(map.clone()));
+    }
+
+    #[test]
+    fn test_content_checksum() {
+        const CAPACITY: usize = 64;
+        let pairs = (0..CAPACITY as u32).map(|k| (k, k.wrapping_mul(0x9e37_79b9)));
+
+        let ascending: SgMap<u32, u32, CAPACITY> = pairs.clone().collect();
+        let descending: SgMap<u32, u32, CAPACITY> = pairs.clone().rev().collect();
+        let mut interleaved = SgMap::<u32, u32, { CAPACITY * 2 }>::new();
+        interleaved.extend(pairs.clone().filter(|(k, _)| k % 2 == 1));
+        interleaved.extend(pairs.filter(|(k, _)| k % 2 == 0));
+
+        // Insertion order and capacity independent
+        let checksum = ascending.content_checksum();
+        assert_eq!(descending.content_checksum(), checksum);
+        assert_eq!(interleaved.content_checksum(), checksum);
+
+        // Value change
+        let mut changed = ascending.clone();
+        *changed.get_mut(&(CAPACITY as u32 / 2)).unwrap() ^= 1;
+        assert_ne!(changed.content_checksum(), checksum);
+
+        // Same bytes as serialized
+        let mut buf = [0; 4 + (CAPACITY * 8)];
+        let len = ascending.serialize_into(&mut buf).unwrap();
+        let mut crc = Crc32::new();
+        crc.write(&buf[..len]);
+        assert_eq!(crc.checksum(), checksum);
+
+        // Byte order fixed, independent of target
+        let single: SgMap<u16, u8, CAPACITY> = [(0x0102, 0x03)].into_iter().collect();
+        let mut crc = Crc32::new();
+        crc.write(&[1, 0, 0, 0, 0x02, 0x01, 0x03]);
+        assert_eq!(single.content_checksum(), crc.checksum());
+
+        // Empty
+        let mut crc = Crc32::new();
+        crc.write(&[0; 4]);
+        assert_eq!(
+            SgMap::<u32, u32, CAPACITY>::new().content_checksum(),
+            crc.checksum()
+        );
+    }
+
+    #[test]
+    fn test_try_for_each() {
+        let map: SgMap<usize, char, 10> = (0..10).zip('a'..).collect();
+        let mut visited = Vec::new();
+
+        let res = map.try_for_each(|k, v| {
+            visited.push(*k);
+            match visited.len() {
+                3 => Err(*v),
+                _ => Ok(()),
+            }
+        });
+
+        // Stops at the third pair
+        assert_eq!(res, Err('c'));
+        assert_eq!(visited, [0, 1, 2]);
+
+        assert_eq!(map.try_for_each(|_, _| Ok::<(), ()>(())), Ok(()));
+    }
+
+    #[test]
+    fn test_arena_stats() {
+        const CAPACITY: usize = 64;
+        let mut map = SgMap::<usize, usize, CAPACITY>::new();
+        assert_eq!(map.arena_stats(), ArenaStats::default());
+
+        // Churn, sliding a window of keys upward
+        for round in 0..4 {
+            map.extend((round * 8..round * 8 + CAPACITY / 2).map(|k| (k, k)));
+            for _ in 0..8 {
+                map.pop_first();
+            }
+
+            let stats = map.arena_stats();
+            assert_eq!(stats.live, map.len());
+            assert!(stats.holes > 0);
+            assert_eq!(stats.holes, stats.backing_len - stats.live);
+            assert!(stats.backing_len <= CAPACITY);
+        }
+
+        map.defragment();
+        let stats = map.arena_stats();
+        assert_eq!(stats.holes, 0);
+        assert_eq!(stats.backing_len, map.len());
+    }
+
+    #[test]
+    #[cfg(feature = "arbitrary")]
+    fn test_arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        const CAPACITY: usize = 8;
+        let bytes: Vec<u8> = (0..=255).cycle().take(4096).collect();
+        let mut u = Unstructured::new(&bytes);
+
+        while !u.is_empty() {
+            let map = SgMap::<u8, u16, CAPACITY>::arbitrary(&mut u).unwrap();
+            assert!(map.len() <= CAPACITY);
+            map.assert_invariants();
+        }
+    }
+
+    #[test]
+    fn test_split_at_key_mut() {
+        const CAPACITY: usize = 16;
+        let mut map: SgMap<usize, usize, CAPACITY> = (0..12).map(|i| (i * 2, 0)).collect();
+
+        // Fragment the arena, so the split is exercised after a sort
+        assert_eq!(map.pop_first(), Some((0, 0)));
+        map.insert(0, 0);
+
+        // Both halves in use at once, from separate threads
+        let (lo, hi) = map.split_at_key_mut(&9);
+        assert_eq!((lo.len(), hi.len()), (5, 7));
+        std::thread::scope(|s| {
+            s.spawn(|| lo.for_each(|(k, v)| *v = *k + 1));
+            s.spawn(|| hi.for_each(|(k, v)| *v = *k + 100));
+        });
+
+        // Partition is correct and each entry was written exactly once
+        for (k, v) in map.iter() {
+            match *k < 9 {
+                true => assert_eq!(*v, *k + 1),
+                false => assert_eq!(*v, *k + 100),
+            }
+        }
+        map.assert_invariants();
+
+        // Split key present, beyond either end, or in an empty map
+        let (lo, hi) = map.split_at_key_mut(&10);
+        assert_eq!(lo.map(|(k, _)| *k).last(), Some(8));
+        assert_eq!(hi.map(|(k, _)| *k).next(), Some(10));
+
+        let (lo, hi) = map.split_at_key_mut(&0);
+        assert_eq!((lo.count(), hi.count()), (0, 12));
+
+        let (lo, hi) = map.split_at_key_mut(&usize::MAX);
+        assert_eq!((lo.count(), hi.count()), (12, 0));
+
+        let mut empty = SgMap::<usize, usize, CAPACITY>::new();
+        let (lo, hi) = empty.split_at_key_mut(&0);
+        assert_eq!((lo.count(), hi.count()), (0, 0));
+    }
+
+    #[test]
+    fn test_try_clone_into() {
+        const CAPACITY: usize = 16;
+        let mut map: SgMap<usize, usize, CAPACITY> = (0..8).map(|i| (i, i * 10)).collect();
+        map.set_rebal_param(0.9, 1.0).unwrap();
+        map.set_max_height(Some(4));
+
+        // Larger
+        let larger = map.try_clone_into::<{ CAPACITY * 2 }>().unwrap();
+        assert_eq!(larger.capacity(), CAPACITY * 2);
+        assert!(larger.iter().eq(map.iter()));
+        assert_eq!(larger.rebal_param(), (0.9, 1.0));
+        assert_eq!(larger.max_height(), Some(4));
+        larger.assert_invariants();
+
+        // Smaller, exact fit
+        let smaller = map.try_clone_into::<8>().unwrap();
+        assert_eq!(smaller.capacity(), 8);
+        assert!(smaller.iter().eq(map.iter()));
+        smaller.assert_invariants();
+
+        // Smaller, doesn't fit
+        assert_eq!(
+            map.try_clone_into::<7>(),
+            Err(SgError::StackCapacityExceeded)
+        );
+        assert_eq!(
+            SgMap::<usize, usize, CAPACITY>::new().try_clone_into::<0>(),
+            Ok(SgMap::new())
+        );
+
+        // Original untouched
+        assert_eq!(map.len(), 8);
+    }
+
+    #[test]
+    fn test_add_assign() {
+        const CAPACITY: usize = 8;
+        let mut a: SgMap<usize, &str, CAPACITY> = SgMap::from_iter([(1, "a"), (2, "b"), (3, "c")]);
+        let b: SgMap<usize, &str, CAPACITY> = SgMap::from_iter([(3, "d"), (4, "e")]);
+
+        // Right-hand side overwrites on collision
+        a += b;
+        assert!(a.into_iter().eq([(1, "a"), (2, "b"), (3, "d"), (4, "e")]));
+
+        // Empty operands
+        let mut a: SgMap<usize, &str, CAPACITY> = SgMap::new();
+        a += SgMap::from_iter([(1, "a")]);
+        a += SgMap::new();
+        assert!(a.iter().eq([(&1, &"a")]));
+
+        // Fallible, unchanged on overflow
+        let full: SgMap<usize, &str, CAPACITY> = (10..(10 + CAPACITY)).map(|i| (i, "x")).collect();
+        assert_eq!(a.try_add(full), Err(SgError::StackCapacityExceeded));
+        assert!(a.iter().eq([(&1, &"a")]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_add_assign_overflow() {
+        const CAPACITY: usize = 4;
+        let mut a: SgMap<usize, usize, CAPACITY> = (0..CAPACITY).map(|i| (i, i)).collect();
+        a += SgMap::from_iter([(CAPACITY, CAPACITY)]);
+    }
+
+    #[test]
+    fn test_try_insert_unique() {
+        const CAPACITY: usize = 4;
+        let mut map: SgMap<usize, &str, CAPACITY> = SgMap::new();
+
+        // Distinct keys
+        assert_eq!(map.try_insert_unique(2, "b"), Ok(()));
+        assert_eq!(map.try_insert_unique(1, "a"), Ok(()));
+        assert!(map.iter().eq([(&1, &"a"), (&2, &"b")]));
+
+        // Duplicate key, value kept
+        assert_eq!(map.try_insert_unique(2, "x"), Err(SgError::DuplicateKey));
+        assert_eq!(map.get(&2), Some(&"b"));
+        assert_eq!(map.len(), 2);
+
+        // Batch, distinct keys
+        assert_eq!(map.try_extend_unique([(4, "d"), (3, "c")]), Ok(()));
+        assert!(map
+            .iter()
+            .eq([(&1, &"a"), (&2, &"b"), (&3, &"c"), (&4, &"d")]));
+
+        // Duplicate detected before capacity when full
+        assert_eq!(map.try_insert_unique(3, "x"), Err(SgError::DuplicateKey));
+        assert_eq!(
+            map.try_insert_unique(5, "e"),
+            Err(SgError::StackCapacityExceeded)
+        );
+
+        // Batch, duplicates within and against map, map unchanged
+        let mut map: SgMap<usize, &str, CAPACITY> = SgMap::from_iter([(1, "a")]);
+        assert_eq!(
+            map.try_extend_unique([(2, "b"), (1, "x")]),
+            Err(SgError::DuplicateKey)
+        );
+        assert_eq!(
+            map.try_extend_unique([(2, "b"), (3, "c"), (2, "x")]),
+            Err(SgError::DuplicateKey)
+        );
+        assert_eq!(
+            map.try_extend_unique([(2, "b"), (3, "c"), (4, "d"), (5, "e")]),
+            Err(SgError::StackCapacityExceeded)
+        );
+        assert!(map.iter().eq([(&1, &"a")]));
+
+        // Empty batch
+        assert_eq!(map.try_extend_unique([]), Ok(()));
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    fn test_rank() {
+        let map: SgMap<usize, &str, 10> =
+            SgMap::from_iter([(40, "d"), (10, "a"), (30, "c"), (20, "b")]);
+
+        // First, middle, last
+        assert_eq!(map.rank(&10), Some(0));
+        assert_eq!(map.rank(&20), Some(1));
+        assert_eq!(map.rank(&30), Some(2));
+        assert_eq!(map.rank(&40), Some(3));
+
+        // Absent: below, between, above
+        assert_eq!(map.rank(&5), None);
+        assert_eq!(map.rank(&25), None);
+        assert_eq!(map.rank(&50), None);
+
+        // Consistent with iteration order
+        for (i, k) in map.keys().enumerate() {
+            assert_eq!(map.rank(k), Some(i));
+        }
+
+        assert_eq!(SgMap::<usize, usize, 10>::new().rank(&0), None);
+    }
+
+    #[test]
+    fn test_retain_collect() {
+        // Small, to steer clear of this crate's intentional two-child removal bug
+        const CAPACITY: usize = 8;
+        let mut map: SgMap<usize, usize, CAPACITY> = (0..CAPACITY).map(|i| (i, i * 10)).collect();
+
+        // Remove odd keys, mutating retained values
+        let removed = map.retain_collect(|k, v| {
+            *v += 1;
+            k % 2 == 0
+        });
+
+        assert!(map.into_iter().eq([(0, 1), (2, 21), (4, 41), (6, 61)]));
+        assert_eq!(removed.capacity(), CAPACITY);
+        assert!(removed.into_iter().eq([(1, 11), (3, 31), (5, 51), (7, 71)]));
+
+        // Nothing removed, everything removed
+        let mut map: SgMap<usize, usize, CAPACITY> = (0..4).map(|i| (i, i)).collect();
+        assert!(map.retain_collect(|_, _| true).is_empty());
+        assert_eq!(map.len(), 4);
+
+        let all = map.retain_collect(|_, _| false);
+        assert!(map.is_empty());
+        assert!(all.into_iter().eq((0..4).map(|i| (i, i))));
+    }
+
+    #[test]
+    fn test_append_capacity() {
+        const CAPACITY: usize = 4;
+        let mut a: SgMap<usize, usize, CAPACITY> = (0..3).map(|i| (i, i)).collect();
+
+        // Union fits exactly, overlapping keys don't count twice
+        let mut b: SgMap<usize, usize, CAPACITY> = (1..4).map(|i| (i, i * 10)).collect();
+        a.append(&mut b);
+        assert!(b.is_empty());
+        assert!(a.into_iter().eq([(0, 0), (1, 10), (2, 20), (3, 30)]));
+
+        // Union overflows, fallible variant leaves both unchanged
+        let mut a: SgMap<usize, usize, CAPACITY> = (0..3).map(|i| (i, i)).collect();
+        let mut b: SgMap<usize, usize, CAPACITY> = (2..5).map(|i| (i, i)).collect();
+        assert_eq!(a.try_append(&mut b), Err(SgError::StackCapacityExceeded));
+        assert_eq!((a.len(), b.len()), (3, 3));
+    }
+
+    #[test]
+    #[should_panic(expected = "Append exceeds stack capacity (5 > 4)")]
+    fn test_append_overflow() {
+        const CAPACITY: usize = 4;
+        let mut a: SgMap<usize, usize, CAPACITY> = (0..3).map(|i| (i, i)).collect();
+        let mut b: SgMap<usize, usize, CAPACITY> = (2..5).map(|i| (i, i)).collect();
+        a.append(&mut b);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_into_keys_values() {
+        const CAPACITY: usize = 16;
+        let map: SgMap<usize, usize, CAPACITY> = (0..CAPACITY).rev().map(|i| (i, i * 3)).collect();
+
+        let (keys, values) = map.into_keys_values();
+        assert_eq!(keys.len(), CAPACITY);
+        assert_eq!(values.len(), CAPACITY);
+        assert!(keys.windows(2).all(|w| w[0] < w[1]));
+        assert!(keys.iter().zip(values.iter()).all(|(k, v)| *v == k * 3));
+
+        let (keys, values) = SgMap::<usize, usize, CAPACITY>::new().into_keys_values();
+        assert!(keys.is_empty() && values.is_empty());
+    }
+
+    #[test]
+    fn test_insert_with_eviction() {
+        const CAPACITY: usize = 4;
+        let mut cache: SgMap<usize, usize, CAPACITY> = SgMap::new();
+        let evict_min = |c: &SgMap<usize, usize, CAPACITY>| c.first_key().copied();
+
+        // Not full
+        for i in 0..CAPACITY {
+            assert_eq!(cache.insert_with_eviction(i, i * 10, evict_min), Ok(None));
+        }
+        assert!(cache.is_full());
+
+        // Full, new keys evict min
+        assert_eq!(
+            cache.insert_with_eviction(10, 100, evict_min),
+            Ok(Some((0, 0)))
+        );
+        assert_eq!(
+            cache.insert_with_eviction(11, 110, evict_min),
+            Ok(Some((1, 10)))
+        );
+        assert!(cache
+            .iter()
+            .eq([(&2, &20), (&3, &30), (&10, &100), (&11, &110)]));
+        cache.assert_invariants();
+
+        // Existing key, value replaced without eviction
+        assert_eq!(cache.insert_with_eviction(2, 21, evict_min), Ok(None));
+        assert_eq!(cache.len(), CAPACITY);
+        assert_eq!(cache.get(&2), Some(&21));
+
+        // No victim, new pair rejected
+        assert_eq!(
+            cache.insert_with_eviction(12, 120, |_| None),
+            Err((12, 120))
+        );
+        assert_eq!(
+            cache.insert_with_eviction(12, 120, |_| Some(99)),
+            Err((12, 120))
+        );
+        assert!(!cache.contains_key(&12));
+        assert_eq!(cache.len(), CAPACITY);
+
+        // Height-bounded, evicts before the map is full instead of panicking
+        let mut cache: SgMap<usize, usize, CAPACITY> = SgMap::new();
+        cache.set_max_height(Some(1));
+        assert_eq!(cache.insert_with_eviction(0, 0, evict_min), Ok(None));
+        assert_eq!(
+            cache.insert_with_eviction(1, 10, evict_min),
+            Ok(Some((0, 0)))
+        );
+        assert!(cache.iter().eq([(&1, &10)]));
+        assert_eq!(cache.insert_with_eviction(2, 20, |_| None), Err((2, 20)));
+
+        // Already over a lowered maximum height, rejected without evicting
+        let mut cache: SgMap<usize, usize, CAPACITY> = (0..3).map(|i| (i, i)).collect();
+        cache.set_max_height(Some(1));
+        assert_eq!(cache.insert_with_eviction(3, 3, evict_min), Err((3, 3)));
+        assert_eq!(cache.len(), 3);
+    }
+
+    #[test]
+    fn test_first_last_key_copied() {
+        const CAPACITY: usize = 16;
+        let mut map: SgMap<u32, &str, CAPACITY> = SgMap::new();
+        assert_eq!(
+            (map.first_key_copied(), map.last_key_copied()),
+            (None, None)
+        );
+
+        map.extend([(9, "i"), (2, "b"), (14, "n")]);
+        assert_eq!(map.first_key_copied(), map.first_key().copied());
+        assert_eq!(map.last_key_copied(), map.last_key().copied());
+        assert_eq!(
+            (map.first_key_copied(), map.last_key_copied()),
+            (Some(2), Some(14))
+        );
+    }
+
+    #[test]
+    fn test_iter_stable_across_reads() {
+        const CAPACITY: usize = 32;
+        let mut map: SgMap<usize, usize, CAPACITY> = (0..CAPACITY).map(|i| (i, i * 2)).collect();
+
+        // Churn, so backing storage order no longer matches key order
+        for _ in 0..4 {
+            let (k, v) = map.pop_first().unwrap();
+            map.pop_last();
+            map.insert(k + CAPACITY, v);
+        }
+        assert!(map.arena_stats().holes > 0);
+        let expected: Vec<(usize, usize)> = map.iter().map(|(k, v)| (*k, *v)).collect();
+
+        // Read-only queries interleaved with iteration
+        let mut visited = Vec::new();
+        for (k, v) in map.iter() {
+            assert_eq!(map.get(k), Some(v));
+            assert_eq!(map.rank(k), Some(visited.len()));
+            assert_eq!(map.get_range(..k).count(), visited.len());
+            assert!(map.iter().eq(expected.iter().map(|(k, v)| (k, v))));
+            assert_eq!(map.arena_stats().live, map.len());
+            visited.push((*k, *v));
+        }
+        assert_eq!(visited, expected);
+
+        // Read-only queries from other threads, while this one iterates
+        let map = &map;
+        std::thread::scope(|s| {
+            let readers: Vec<_> = (0..4)
+                .map(|_| {
+                    s.spawn(|| {
+                        for (k, v) in expected.iter() {
+                            assert_eq!(map.get(k), Some(v));
+                            assert_eq!(
+                                map.first_key_value(),
+                                expected.first().map(|(k, v)| (k, v))
+                            );
+                        }
+                    })
+                })
+                .collect();
+
+            assert!(map.iter().eq(expected.iter().map(|(k, v)| (k, v))));
+            readers.into_iter().for_each(|r| r.join().unwrap());
+        });
+    }
+}
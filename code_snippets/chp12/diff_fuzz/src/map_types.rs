@@ -1,6 +1,10 @@
+use core::borrow::Borrow;
+use core::ops::RangeBounds;
+
 use crate::map::SgMap;
 use crate::tree::{
-    Idx, IntoIter as TreeIntoIter, Iter as TreeIter, IterMut as TreeIterMut, SmallNode,
+    Idx, IntoIter as TreeIntoIter, Iter as TreeIter, IterMut as TreeIterMut,
+    RangeMut as TreeRangeMut, SmallNode,
 };
 
 // General Iterators ---------------------------------------------------------------------------------------------------
@@ -8,8 +12,21 @@ use crate::tree::{
 /// An iterator over the entries of a [`SgMap`][crate::map::SgMap].
 ///
 /// This `struct` is created by the [`iter`][crate::map::SgMap::iter] method on [`SgMap`][crate::map::SgMap].
-/// documentation for more.
+/// See its documentation for more.
+///
+/// The iterator holds a shared borrow of the map, so it stays valid across other `&self` calls (e.g. lookups or
+/// other iterators), none of which modify the map.
+/// Methods taking `&mut self`, including those that reorder backing storage (e.g. [`retain`][crate::map::SgMap::retain]
+/// or [`iter_mut`][crate::map::SgMap::iter_mut]), can't be called until the iterator is dropped:
 ///
+/// ```compile_fail
+/// use buggy_scapegoat::SgMap;
+///
+/// let mut map: SgMap<usize, usize, 10> = (0..5).map(|i| (i, i)).collect();
+/// let mut iter = map.iter();
+/// map.retain(|k, _| k % 2 == 0);
+/// iter.next();
+/// ```
 pub struct Iter<'a, T: Ord + Default, V: Default, const N: usize> {
     ref_iter: TreeIter<'a, T, V, N>,
 }
@@ -29,6 +46,11 @@ impl<'a, K: Ord + Default, V: Default, const N: usize> Iterator for Iter<'a, K,
     fn next(&mut self) -> Option<Self::Item> {
         self.ref_iter.next()
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
 }
 
 impl<'a, K: Ord + Default, V: Default, const N: usize> ExactSizeIterator for Iter<'a, K, V, N> {
@@ -37,6 +59,14 @@ impl<'a, K: Ord + Default, V: Default, const N: usize> ExactSizeIterator for Ite
     }
 }
 
+impl<'a, K: Ord + Default, V: Default, const N: usize> Clone for Iter<'a, K, V, N> {
+    fn clone(&self) -> Self {
+        Iter {
+            ref_iter: self.ref_iter.clone(),
+        }
+    }
+}
+
 /// An owning iterator over the entries of a [`SgMap`][crate::map::SgMap].
 ///
 /// This `struct` is created by the [`into_iter`][crate::map::SgMap::into_iter] method on [`SgMap`][crate::map::SgMap].
@@ -60,6 +90,17 @@ impl<K: Ord + Default, V: Default, const N: usize> Iterator for IntoIter<K, V, N
     fn next(&mut self) -> Option<Self::Item> {
         self.cons_iter.next()
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<K: Ord + Default, V: Default, const N: usize> DoubleEndedIterator for IntoIter<K, V, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.cons_iter.next_back()
+    }
 }
 
 impl<K: Ord + Default, V: Default, const N: usize> ExactSizeIterator for IntoIter<K, V, N> {
@@ -83,6 +124,16 @@ impl<'a, K: Ord + Default, V: Default, const N: usize> IterMut<'a, K, V, N> {
             mut_iter: TreeIterMut::new(&mut map.bst),
         }
     }
+
+    /// Construct pair of disjoint iterators, split at a key.
+    pub(crate) fn new_split<Q>(map: &'a mut SgMap<K, V, N>, key: &Q) -> (Self, Self)
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let (lo_iter, hi_iter) = TreeIterMut::new_split(&mut map.bst, key);
+        (IterMut { mut_iter: lo_iter }, IterMut { mut_iter: hi_iter })
+    }
 }
 
 impl<'a, K: Ord + Default, V: Default, const N: usize> Iterator for IterMut<'a, K, V, N> {
@@ -91,6 +142,11 @@ impl<'a, K: Ord + Default, V: Default, const N: usize> Iterator for IterMut<'a,
     fn next(&mut self) -> Option<Self::Item> {
         self.mut_iter.next()
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
 }
 
 impl<'a, K: Ord + Default, V: Default, const N: usize> ExactSizeIterator for IterMut<'a, K, V, N> {
@@ -99,6 +155,47 @@ impl<'a, K: Ord + Default, V: Default, const N: usize> ExactSizeIterator for Ite
     }
 }
 
+/// A mutable iterator over a sub-range of entries in a [`SgMap`][crate::map::SgMap].
+///
+/// This `struct` is created by the [`range_mut`][crate::map::SgMap::range_mut] method on [`SgMap`][crate::map::SgMap].
+/// See its documentation for more.
+pub struct RangeMut<'a, K: Ord + Default, V: Default, const N: usize> {
+    mut_iter: TreeRangeMut<'a, K, V, N>,
+}
+
+impl<'a, K: Ord + Default, V: Default, const N: usize> RangeMut<'a, K, V, N> {
+    /// Construct bounded mutable iterator.
+    pub(crate) fn new<Q, R>(map: &'a mut SgMap<K, V, N>, range: R) -> Self
+    where
+        K: Borrow<Q>,
+        R: RangeBounds<Q>,
+        Q: Ord + ?Sized,
+    {
+        RangeMut {
+            mut_iter: TreeRangeMut::new(&mut map.bst, range),
+        }
+    }
+}
+
+impl<'a, K: Ord + Default, V: Default, const N: usize> Iterator for RangeMut<'a, K, V, N> {
+    type Item = (&'a K, &'a mut V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.mut_iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, K: Ord + Default, V: Default, const N: usize> ExactSizeIterator for RangeMut<'a, K, V, N> {
+    fn len(&self) -> usize {
+        self.mut_iter.len()
+    }
+}
+
 // Key Iterators -------------------------------------------------------------------------------------------------------
 
 // TODO: these need more trait implementations for full compatibility
@@ -117,6 +214,11 @@ impl<'a, K: Ord + Default, V: Default, const N: usize> Iterator for Keys<'a, K,
     fn next(&mut self) -> Option<&'a K> {
         self.inner.next().map(|(k, _)| k)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
 }
 
 impl<'a, K: Ord + Default, V: Default, const N: usize> ExactSizeIterator for Keys<'a, K, V, N> {
@@ -125,6 +227,14 @@ impl<'a, K: Ord + Default, V: Default, const N: usize> ExactSizeIterator for Key
     }
 }
 
+impl<'a, K: Ord + Default, V: Default, const N: usize> Clone for Keys<'a, K, V, N> {
+    fn clone(&self) -> Self {
+        Keys {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
 /// An owning iterator over the keys of a [`SgMap`][crate::map::SgMap].
 ///
 /// This `struct` is created by the [`into_keys`][crate::map::SgMap::into_keys] method on [`SgMap`][crate::map::SgMap].
@@ -139,6 +249,17 @@ impl<K: Ord + Default, V: Default, const N: usize> Iterator for IntoKeys<K, V, N
     fn next(&mut self) -> Option<K> {
         self.inner.next().map(|(k, _)| k)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<K: Ord + Default, V: Default, const N: usize> DoubleEndedIterator for IntoKeys<K, V, N> {
+    fn next_back(&mut self) -> Option<K> {
+        self.inner.next_back().map(|(k, _)| k)
+    }
 }
 
 impl<K: Ord + Default, V: Default, const N: usize> ExactSizeIterator for IntoKeys<K, V, N> {
@@ -165,6 +286,11 @@ impl<'a, K: Ord + Default, V: Default, const N: usize> Iterator for Values<'a, K
     fn next(&mut self) -> Option<&'a V> {
         self.inner.next().map(|(_, v)| v)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
 }
 
 impl<'a, K: Ord + Default, V: Default, const N: usize> ExactSizeIterator for Values<'a, K, V, N> {
@@ -173,6 +299,14 @@ impl<'a, K: Ord + Default, V: Default, const N: usize> ExactSizeIterator for Val
     }
 }
 
+impl<'a, K: Ord + Default, V: Default, const N: usize> Clone for Values<'a, K, V, N> {
+    fn clone(&self) -> Self {
+        Values {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
 /// An owning iterator over the values of a [`SgMap`][crate::map::SgMap].
 ///
 /// This `struct` is created by the [`into_values`][crate::map::SgMap::into_values] method on [`SgMap`][crate::map::SgMap].
@@ -187,6 +321,17 @@ impl<K: Ord + Default, V: Default, const N: usize> Iterator for IntoValues<K, V,
     fn next(&mut self) -> Option<V> {
         self.inner.next().map(|(_, v)| v)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<K: Ord + Default, V: Default, const N: usize> DoubleEndedIterator for IntoValues<K, V, N> {
+    fn next_back(&mut self) -> Option<V> {
+        self.inner.next_back().map(|(_, v)| v)
+    }
 }
 
 impl<K: Ord + Default, V: Default, const N: usize> ExactSizeIterator for IntoValues<K, V, N> {
@@ -209,6 +354,11 @@ impl<'a, K: Ord + Default, V: Default, const N: usize> Iterator for ValuesMut<'a
     fn next(&mut self) -> Option<&'a mut V> {
         self.inner.next().map(|(_, v)| v)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
 }
 
 impl<'a, K: Ord + Default, V: Default, const N: usize> ExactSizeIterator
@@ -600,3 +750,331 @@ impl<'a, K: Ord + Default, V: Default, const N: usize> OccupiedEntry<'a, K, V, N
         self.remove_entry().1
     }
 }
+
+/// A view into a single entry in a map, looked up by a borrowed form of the key.
+/// An owned key is only constructed, via `From<&Q>`, if a value is inserted into a vacant entry.
+///
+/// This `enum` is constructed from the [`SgMap::entry_ref`] method on [`SgMap`].
+pub enum EntryRef<'a, K: Ord + Default, Q: ?Sized, V: Default, const N: usize> {
+    /// A vacant entry.
+    Vacant(VacantEntryRef<'a, K, Q, V, N>),
+    /// An occupied entry.
+    Occupied(OccupiedEntry<'a, K, V, N>),
+}
+
+impl<'a, K, Q, V, const N: usize> EntryRef<'a, K, Q, V, N>
+where
+    K: Ord + Default + Borrow<Q> + From<&'a Q>,
+    Q: Ord + ?Sized,
+    V: Default,
+{
+    /// Ensures a value is in the entry by inserting the default if empty, and returns a mutable
+    /// reference to the value in the entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<String, usize, 10>::new();
+    /// map.entry_ref("poneyland").or_insert(12);
+    ///
+    /// assert_eq!(map["poneyland"], 12);
+    /// ```
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        match self {
+            EntryRef::Occupied(entry) => entry.into_mut(),
+            EntryRef::Vacant(entry) => entry.insert(default),
+        }
+    }
+
+    /// Ensures a value is in the entry by inserting the result of the default function if empty, and returns a mutable
+    /// reference to the value in the entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<String, usize, 10>::new();
+    /// let x = 42;
+    /// map.entry_ref("poneyland").or_insert_with(|| x);
+    ///
+    /// assert_eq!(map["poneyland"], 42);
+    /// ```
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            EntryRef::Occupied(entry) => entry.into_mut(),
+            EntryRef::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Returns a reference to this entry's key, in borrowed form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<String, usize, 10>::new();
+    /// assert_eq!(map.entry_ref("poneyland").key(), "poneyland");
+    /// ```
+    pub fn key(&self) -> &Q {
+        match self {
+            EntryRef::Occupied(entry) => entry.key().borrow(),
+            EntryRef::Vacant(entry) => entry.key(),
+        }
+    }
+
+    /// Provides in-place mutable access to an occupied entry before any
+    /// potential inserts into the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<String, usize, 10>::new();
+    ///
+    /// map.entry_ref("poneyland")
+    ///    .and_modify(|e| { *e += 1 })
+    ///    .or_insert(42);
+    /// assert_eq!(map["poneyland"], 42);
+    ///
+    /// map.entry_ref("poneyland")
+    ///    .and_modify(|e| { *e += 1 })
+    ///    .or_insert(42);
+    /// assert_eq!(map["poneyland"], 43);
+    /// ```
+    pub fn and_modify<F: FnOnce(&mut V)>(self, f: F) -> EntryRef<'a, K, Q, V, N> {
+        match self {
+            EntryRef::Occupied(mut entry) => {
+                f(entry.get_mut());
+                EntryRef::Occupied(entry)
+            }
+            EntryRef::Vacant(entry) => EntryRef::Vacant(entry),
+        }
+    }
+
+    /// Ensures a value is in the entry by inserting the default value if empty,
+    /// and returns a mutable reference to the value in the entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<String, Option<usize>, 10>::new();
+    /// map.entry_ref("poneyland").or_default();
+    ///
+    /// assert_eq!(map["poneyland"], None);
+    /// ```
+    pub fn or_default(self) -> &'a mut V {
+        match self {
+            EntryRef::Occupied(entry) => entry.into_mut(),
+            EntryRef::Vacant(entry) => entry.insert(Default::default()),
+        }
+    }
+}
+
+/// A view into a vacant entry in a [`SgMap`][crate::map::SgMap], holding a borrowed form of the key.
+/// It is part of the [`EntryRef`] enum.
+pub struct VacantEntryRef<'a, K: Ord + Default, Q: ?Sized, V: Default, const N: usize> {
+    pub(super) key: &'a Q,
+    pub(super) table: &'a mut SgMap<K, V, N>,
+}
+
+impl<'a, K, Q, V, const N: usize> VacantEntryRef<'a, K, Q, V, N>
+where
+    K: Ord + Default + Borrow<Q> + From<&'a Q>,
+    Q: Ord + ?Sized,
+    V: Default,
+{
+    /// Gets a reference to the borrowed key that would be used when inserting a value
+    /// through the [`VacantEntryRef`][crate::map_types::VacantEntryRef].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgMap;
+    ///
+    /// let mut map = SgMap::<String, usize, 2>::new();
+    /// assert_eq!(map.entry_ref("poneyland").key(), "poneyland");
+    /// ```
+    pub fn key(&self) -> &'a Q {
+        self.key
+    }
+
+    /// Converts the borrowed key to an owned key, sets the value of the entry with it,
+    /// and returns a mutable reference to the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgMap;
+    /// use buggy_scapegoat::map_types::EntryRef;
+    ///
+    /// let mut map = SgMap::<String, u32, 2>::new();
+    ///
+    /// if let EntryRef::Vacant(v) = map.entry_ref("poneyland") {
+    ///     v.insert(37);
+    /// }
+    /// assert_eq!(map["poneyland"], 37);
+    /// ```
+    pub fn insert(self, value: V) -> &'a mut V {
+        let (_, new_node_idx) = self
+            .table
+            .bst
+            .priv_balancing_insert::<Idx>(K::from(self.key), value);
+
+        self.table.bst.arena[new_node_idx].get_mut().1
+    }
+}
+
+// Builder -------------------------------------------------------------------------------------------------------------
+
+/// An incremental builder for a [`SgMap`][crate::map::SgMap], for pairs arriving one at a time.
+/// Once the map is full, new keys are rejected and handed back to the caller.
+///
+/// This `struct` is created by the [`builder`][crate::map::SgMap::builder] method on [`SgMap`][crate::map::SgMap].
+pub struct MapBuilder<K: Ord + Default, V: Default, const N: usize> {
+    pub(super) map: SgMap<K, V, N>,
+}
+
+impl<K: Ord + Default, V: Default, const N: usize> MapBuilder<K, V, N> {
+    /// Adds a pair, updating the value if the key was already pushed.
+    /// If the map is full and the key is new, returns ownership of the rejected pair.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgMap;
+    ///
+    /// let mut builder = SgMap::<_, _, 1>::builder();
+    /// assert_eq!(builder.push(1, "a"), Ok(()));
+    /// assert_eq!(builder.push(1, "b"), Ok(()));
+    /// assert_eq!(builder.push(2, "c"), Err((2, "c")));
+    /// ```
+    pub fn push(&mut self, key: K, val: V) -> Result<(), (K, V)> {
+        if self.map.is_full() && !self.map.contains_key(&key) {
+            return Err((key, val));
+        }
+
+        self.map.insert(key, val);
+        Ok(())
+    }
+
+    /// Returns `true` if pushing a new key would be rejected.
+    pub fn is_full(&self) -> bool {
+        self.map.is_full()
+    }
+
+    /// Finishes building, returning the map.
+    pub fn finish(self) -> SgMap<K, V, N> {
+        self.map
+    }
+}
+
+// Test ----------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::map::SgMap;
+    use core::borrow::Borrow;
+    use core::cell::Cell;
+
+    thread_local! {
+        static FROM_CNT: Cell<usize> = const { Cell::new(0) };
+    }
+
+    // Owned key type that counts conversions from its borrowed form
+    #[derive(Default, PartialEq, Eq, PartialOrd, Ord)]
+    struct CountedKey(String);
+
+    impl Borrow<str> for CountedKey {
+        fn borrow(&self) -> &str {
+            &self.0
+        }
+    }
+
+    impl From<&str> for CountedKey {
+        fn from(key: &str) -> Self {
+            FROM_CNT.with(|cnt| cnt.set(cnt.get() + 1));
+            CountedKey(key.to_string())
+        }
+    }
+
+    #[test]
+    fn test_entry_ref_converts_on_miss_only() {
+        let mut map = SgMap::<CountedKey, usize, 10>::new();
+
+        for key in ["a", "b", "a", "c", "a", "b"] {
+            *map.entry_ref(key).or_insert(0) += 1;
+        }
+
+        assert_eq!(FROM_CNT.with(|cnt| cnt.get()), 3);
+        assert_eq!(map["a"], 3);
+        assert_eq!(map["b"], 2);
+        assert_eq!(map["c"], 1);
+    }
+
+    #[test]
+    fn test_map_builder() {
+        const CAPACITY: usize = 4;
+        let mut builder = SgMap::<usize, String, CAPACITY>::builder();
+        let mut rejected = Vec::new();
+
+        for i in (0..(CAPACITY * 2)).rev() {
+            if let Err(pair) = builder.push(i, i.to_string()) {
+                rejected.push(pair);
+            }
+        }
+
+        // Updates accepted even when full
+        assert!(builder.is_full());
+        assert_eq!(builder.push(7, String::from("seven")), Ok(()));
+
+        // Ownership of rejected pairs returned
+        assert_eq!(rejected.len(), CAPACITY);
+        assert!(rejected.iter().map(|(k, v)| (*k, v.as_str())).eq([
+            (3, "3"),
+            (2, "2"),
+            (1, "1"),
+            (0, "0")
+        ]));
+
+        let map = builder.finish();
+        assert!(map.keys().copied().eq(CAPACITY..(CAPACITY * 2)));
+        assert_eq!(map[&7], "seven");
+        map.assert_invariants();
+    }
+
+    #[test]
+    fn test_iter_clone() {
+        // Values needn't be `Clone` for their iterators to be
+        #[derive(Debug, Default, PartialEq)]
+        struct NotClone(usize);
+
+        let map: SgMap<usize, NotClone, 8> = (0..5).map(|i| (i, NotClone(i * 10))).collect();
+
+        let mut iter = map.iter();
+        iter.nth(1);
+        let mut iter_clone = iter.clone();
+        assert_eq!(iter.next(), Some((&2, &NotClone(20))));
+        assert_eq!(iter.next(), Some((&3, &NotClone(30))));
+        assert_eq!(iter_clone.len(), 3);
+        assert_eq!(iter_clone.next(), Some((&2, &NotClone(20))));
+        assert!(iter.eq(iter_clone.skip(1)));
+
+        let mut keys = map.keys();
+        keys.next();
+        assert!(keys.clone().eq(keys.by_ref()));
+        assert_eq!(keys.next(), None);
+
+        let mut values = map.values();
+        values.next();
+        let values_clone = values.clone();
+        assert_eq!(values.next(), Some(&NotClone(10)));
+        assert!(values_clone.map(|v| v.0).eq([10, 20, 30, 40]));
+    }
+}
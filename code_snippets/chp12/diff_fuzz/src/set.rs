@@ -1,10 +1,19 @@
 use core::borrow::Borrow;
+use core::cmp::Ordering;
 use core::fmt::{self, Debug};
 use core::iter::FromIterator;
-use core::ops::{BitAnd, BitOr, BitXor, Sub};
+use core::ops::{BitAnd, BitOr, BitXor, RangeBounds, Sub};
 
-use crate::set_types::{Difference, Intersection, IntoIter, Iter, SymmetricDifference, Union};
-use crate::tree::{SgError, SgTree};
+use crate::set_types::{
+    Difference, Intersection, IntoIter, Iter, SetBuilder, SymmetricDifference, Union,
+};
+use crate::tree::{ArenaStats, SgError, SgTree};
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+#[allow(unused_imports)] // micromath only used if `no_std`
+use micromath::F32Ext;
 
 /// Safe, fallible, embedded-friendly ordered set.
 ///
@@ -13,8 +22,15 @@ use crate::tree::{SgError, SgTree};
 /// * [`try_insert`][crate::set::SgSet::try_insert]
 /// * [`try_append`][crate::set::SgSet::try_append]
 /// * [`try_extend`][crate::set::SgSet::try_extend]
+/// * [`try_extend_from_slice`][crate::set::SgSet::try_extend_from_slice]
+/// * [`try_insert_sorted`][crate::set::SgSet::try_insert_sorted]
+/// * [`try_merge_sorted`][crate::set::SgSet::try_merge_sorted]
 /// * [`try_from_iter`][crate::set::SgSet::try_from_iter]
+/// * [`collect_checked`][crate::set::SgSet::collect_checked]
+/// * [`try_clone_into`][crate::set::SgSet::try_clone_into]
+/// * [`try_partition`][crate::set::SgSet::try_partition]
 /// * [`try_replace`][crate::set::SgSet::try_replace]
+/// * [`get_or_insert`][crate::set::SgSet::get_or_insert]
 ///
 /// [`TryFrom`](https://doc.rust-lang.org/stable/std/convert/trait.TryFrom.html) isn't implemented because it would collide with the blanket implementation.
 /// See [this open GitHub issue](https://github.com/rust-lang/rust/issues/50133#issuecomment-64690839) from 2018,
@@ -30,6 +46,33 @@ pub struct SgSet<T: Ord + Default, const N: usize> {
 }
 
 impl<T: Ord + Default, const N: usize> SgSet<T, N> {
+    /// An empty `SgSet`, usable in `const` and `static` initializers (unlike [`new`][crate::set::SgSet::new]).
+    /// Exceeding the maximum capacity (`u16::MAX`) is a compile-time error, instead of a runtime panic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgSet;
+    ///
+    /// const NO_IDS: SgSet<u32, 8> = SgSet::EMPTY;
+    ///
+    /// let mut ids = NO_IDS;
+    /// ids.insert(42);
+    /// assert!(NO_IDS.is_empty());
+    /// assert!(ids.contains(&42));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use buggy_scapegoat::SgSet;
+    ///
+    /// const TOO_BIG: SgSet<u8, { u16::MAX as usize + 1 }> = SgSet::EMPTY;
+    /// ```
+    ///
+    /// ### Note
+    ///
+    /// `new` can't be a `const fn`: it builds storage via `Default`, and trait methods aren't `const` on stable.
+    pub const EMPTY: Self = SgSet { bst: SgTree::EMPTY };
+
     /// Makes a new, empty `SgSet`.
     ///
     /// # Examples
@@ -43,6 +86,26 @@ impl<T: Ord + Default, const N: usize> SgSet<T, N> {
         SgSet { bst: SgTree::new() }
     }
 
+    /// Makes a new, empty [`SetBuilder`] for adding values one at a time, with rejected values returned once full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgSet;
+    ///
+    /// let mut builder = SgSet::<_, 2>::builder();
+    /// for value in [3, 1, 2] {
+    ///     if let Err(rejected) = builder.push(value) {
+    ///         assert_eq!(rejected, 2);
+    ///     }
+    /// }
+    ///
+    /// assert!(builder.finish().into_iter().eq([1, 3]));
+    /// ```
+    pub fn builder() -> SetBuilder<T, N> {
+        SetBuilder { set: SgSet::new() }
+    }
+
     /// The [original scapegoat tree paper's](https://people.csail.mit.edu/rivest/pubs/GR93.pdf) alpha, `a`, can be chosen in the range `0.5 <= a < 1.0`.
     /// `a` tunes how "aggressively" the data structure self-balances.
     /// It controls the trade-off between total rebuild time and maximum height guarantees.
@@ -93,6 +156,139 @@ impl<T: Ord + Default, const N: usize> SgSet<T, N> {
         self.bst.rebal_param()
     }
 
+    /// Suggest a rebalance parameter, alpha, for the workload observed so far, as `(alpha_numerator, alpha_denominator)`.
+    /// This is a heuristic: frequent rebuilds (e.g. sorted inserts) trend toward `1.0`, rare rebuilds toward `0.5`.
+    /// See [the corresponding setter method][SgSet::set_rebal_param] for the trade-off.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgSet;
+    ///
+    /// let mut set: SgSet<usize, 64> = SgSet::new();
+    ///
+    /// // Sorted order, insert-heavy
+    /// set.extend(0..64);
+    ///
+    /// let (num, denom) = set.suggest_alpha();
+    /// assert!(num / denom > 2.0 / 3.0);
+    /// ```
+    #[doc(alias = "alpha")]
+    pub fn suggest_alpha(&self) -> (f32, f32) {
+        self.bst.suggest_alpha()
+    }
+
+    /// Apply the rebalance parameter from [`suggest_alpha`][SgSet::suggest_alpha], for subsequent operations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgSet;
+    ///
+    /// let mut set: SgSet<usize, 64> = SgSet::new();
+    /// set.extend(0..64);
+    ///
+    /// let suggested = set.suggest_alpha();
+    /// set.auto_tune_alpha();
+    /// assert_eq!(set.rebal_param(), suggested);
+    /// ```
+    #[doc(alias = "alpha")]
+    pub fn auto_tune_alpha(&mut self) {
+        self.bst.auto_tune_alpha()
+    }
+
+    /// Reset the rebalance count, which [`suggest_alpha`][SgSet::suggest_alpha] uses to infer workload, to zero.
+    /// Contents are unchanged. Useful when starting a new workload phase, so prior operations don't skew suggestions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgSet;
+    ///
+    /// let mut set: SgSet<usize, 64> = SgSet::new();
+    ///
+    /// // Sorted order, insert-heavy
+    /// set.extend(0..64);
+    /// assert!(set.suggest_alpha() > (50.0, 100.0));
+    ///
+    /// // No rebuilds observed since reset
+    /// set.reset_rebal_cnt();
+    /// assert_eq!(set.suggest_alpha(), (50.0, 100.0));
+    /// assert_eq!(set.len(), 64);
+    /// ```
+    pub fn reset_rebal_cnt(&mut self) {
+        self.bst.reset_rebal_cnt()
+    }
+
+    /// Set a strict maximum tree height, or `None` for no limit (default), to bound worst-case lookup cost.
+    /// Any insert that would exceed the limit triggers an immediate full rebuild, trading insert cost for the bound.
+    ///
+    /// A tree of height `h` holds at most `2^h - 1` elements.
+    /// Fallible inserts return [`SgError::MaximumHeightExceeded`] if a new element wouldn't fit within the limit,
+    /// infallible inserts panic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::{SgSet, SgError};
+    ///
+    /// let mut set: SgSet<usize, 10> = SgSet::new();
+    /// set.set_max_height(Some(3));
+    ///
+    /// // Sorted order, worst case
+    /// for i in 0..7 {
+    ///     set.insert(i);
+    /// }
+    ///
+    /// assert_eq!(set.try_insert(7), Err(SgError::MaximumHeightExceeded));
+    /// ```
+    pub fn set_max_height(&mut self, max: Option<usize>) {
+        self.bst.set_max_height(max)
+    }
+
+    /// Get the maximum tree height, if any.
+    /// See [the corresponding setter method][SgSet::set_max_height] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgSet;
+    ///
+    /// let mut set: SgSet<usize, 10> = SgSet::new();
+    /// assert_eq!(set.max_height(), None);
+    ///
+    /// set.set_max_height(Some(4));
+    /// assert_eq!(set.max_height(), Some(4));
+    /// ```
+    pub fn max_height(&self) -> Option<usize> {
+        self.bst.max_height()
+    }
+
+    /// Set a callback invoked whenever the set rebuilds a subtree, e.g. to log rebalancing on-device.
+    /// The callback receives the internal index of the rebuilt subtree's root (the scapegoat) and the subtree's size.
+    /// Zero-cost when unset (default).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgSet;
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    ///
+    /// static REBUILD_CNT: AtomicUsize = AtomicUsize::new(0);
+    ///
+    /// let mut set = SgSet::<usize, 10>::new();
+    /// set.set_rebalance_callback(|_, _| {
+    ///     REBUILD_CNT.fetch_add(1, Ordering::Relaxed);
+    /// });
+    ///
+    /// // Sorted insertion, worst case
+    /// set.extend(0..10);
+    /// assert!(REBUILD_CNT.load(Ordering::Relaxed) > 0);
+    /// ```
+    pub fn set_rebalance_callback(&mut self, cb: fn(scapegoat_idx: usize, subtree_size: usize)) {
+        self.bst.set_rebalance_callback(cb)
+    }
+
     /// Total capacity, e.g. maximum number of set elements.
     ///
     /// # Examples
@@ -108,8 +304,29 @@ impl<T: Ord + Default, const N: usize> SgSet<T, N> {
         self.bst.capacity()
     }
 
+    /// Total capacity, e.g. maximum number of set elements, usable in const contexts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgSet;
+    ///
+    /// const CAPACITY: usize = SgSet::<i32, 10>::max_capacity_const();
+    /// let buf = [0_u8; CAPACITY];
+    ///
+    /// assert!(buf.len() == 10);
+    /// ```
+    pub const fn max_capacity_const() -> usize {
+        N
+    }
+
     /// Moves all elements from `other` into `self`, leaving `other` empty.
     ///
+    /// # Panics
+    ///
+    /// Panics if the union of `self` and `other` exceeds the capacity `N`, leaving both unchanged.
+    /// Use [`try_append`][crate::set::SgSet::try_append] to handle this case.
+    ///
     /// # Examples
     ///
     /// ```
@@ -259,10 +476,9 @@ impl<T: Ord + Default, const N: usize> SgSet<T, N> {
     where
         T: Ord,
     {
-        match self.bst.try_insert(value, ()) {
-            Ok(opt_val) => Ok(opt_val.is_none()),
-            Err(_) => Err(SgError::StackCapacityExceeded),
-        }
+        self.bst
+            .try_insert(value, ())
+            .map(|opt_val| opt_val.is_none())
     }
 
     /// Attempt to extend a collection with the contents of an iterator.
@@ -300,6 +516,143 @@ impl<T: Ord + Default, const N: usize> SgSet<T, N> {
         }
     }
 
+    /// Extend the set with the contents of an iterator, stopping at the first value that can't be inserted
+    /// (the set is full, or the [maximum height][SgSet::set_max_height] is reached).
+    /// The rest of the iterator is left unconsumed, so an unbounded iterator is fine.
+    /// Returns the number of new values inserted, values already present aren't counted.
+    /// Unlike [`Extend`], never panics for lack of capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgSet;
+    ///
+    /// let mut set = SgSet::<_, 3>::new();
+    /// set.insert(1);
+    ///
+    /// assert_eq!(set.saturating_extend([1, 2, 3, 4, 5]), 2);
+    /// assert!(set.is_full());
+    /// assert!(set.iter().eq([1, 2, 3].iter()));
+    ///
+    /// // Stops when full, even for an unbounded iterator
+    /// assert_eq!(set.saturating_extend(0..), 0);
+    /// ```
+    pub fn saturating_extend<I: IntoIterator<Item = T>>(&mut self, iter: I) -> usize
+    where
+        T: Ord,
+    {
+        let mut inserted_cnt = 0;
+        for v in iter {
+            match self.try_insert(v) {
+                Ok(true) => inserted_cnt += 1,
+                Ok(false) => {}
+                Err(_) => break,
+            }
+        }
+
+        inserted_cnt
+    }
+
+    /// Attempt to extend a collection with the contents of an iterator, optimized for ascending (e.g. sorted or
+    /// nearly-sorted) input: in-order elements are appended and the set rebalances once, at the end, instead of
+    /// potentially per-insert. Out-of-order elements are still inserted correctly, just without the speedup.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::{SgSet, SgError};
+    ///
+    /// let mut set = SgSet::<_, 10>::new();
+    ///
+    /// // Too big
+    /// assert_eq!(set.try_insert_sorted(0..11), Err(SgError::StackCapacityExceeded));
+    ///
+    /// // Fits, mostly sorted
+    /// assert!(set.try_insert_sorted([0, 1, 2, 5, 3, 4, 6].into_iter()).is_ok());
+    /// assert!(set.into_iter().eq(0..7));
+    /// ```
+    pub fn try_insert_sorted<I: ExactSizeIterator<Item = T>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), SgError> {
+        self.bst.try_insert_sorted(iter.map(|e| (e, ())))
+    }
+
+    /// Attempt to construct a new set from the union of two sets.
+    /// Both sets are already in order, so a single in-order merge yields ascending, deduplicated values that are
+    /// appended and rebalanced once, at the end - instead of potentially per-insert, like [`BitOr`] (`a | b`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::{SgSet, SgError};
+    ///
+    /// let a = SgSet::<_, 5>::from_iter([1, 3, 5]);
+    /// let b = SgSet::<_, 5>::from_iter([1, 2, 4]);
+    ///
+    /// // Fits
+    /// let merged = SgSet::try_merge_sorted(&a, &b).unwrap();
+    /// assert!(merged.iter().eq(&[1, 2, 3, 4, 5]));
+    ///
+    /// // Too big
+    /// let c = SgSet::<_, 5>::from_iter([6]);
+    /// assert_eq!(SgSet::try_merge_sorted(&merged, &c), Err(SgError::StackCapacityExceeded));
+    /// ```
+    pub fn try_merge_sorted(a: &SgSet<T, N>, b: &SgSet<T, N>) -> Result<SgSet<T, N>, SgError>
+    where
+        T: Clone + Ord,
+    {
+        let mut merged = SgSet::new();
+        if a.union_count(b) > merged.capacity() {
+            return Err(SgError::StackCapacityExceeded);
+        }
+
+        let (mut a_iter, mut b_iter) = (a.iter().peekable(), b.iter().peekable());
+        let union_iter = core::iter::from_fn(|| match (a_iter.peek(), b_iter.peek()) {
+            (Some(a_val), Some(b_val)) => match a_val.cmp(b_val) {
+                Ordering::Less => a_iter.next(),
+                Ordering::Greater => b_iter.next(),
+                Ordering::Equal => {
+                    b_iter.next();
+                    a_iter.next()
+                }
+            },
+            (Some(_), None) => a_iter.next(),
+            (None, _) => b_iter.next(),
+        });
+
+        merged
+            .bst
+            .priv_insert_sorted(union_iter.map(|val| (val.clone(), ())));
+
+        Ok(merged)
+    }
+
+    /// Attempt to extend a collection with copies of a slice's elements.
+    /// Only elements not already in the set count against remaining capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::{SgSet, SgError};
+    ///
+    /// let mut set = SgSet::<_, 4>::from_iter([1, 2]);
+    ///
+    /// // Fits, duplicates and existing elements don't take up capacity
+    /// assert!(set.try_extend_from_slice(&[2, 3, 3, 1]).is_ok());
+    /// assert!(set.iter().eq([1, 2, 3].iter()));
+    ///
+    /// // Too big, set left unchanged
+    /// assert_eq!(set.try_extend_from_slice(&[4, 5]), Err(SgError::StackCapacityExceeded));
+    /// assert!(set.iter().eq([1, 2, 3].iter()));
+    /// ```
+    pub fn try_extend_from_slice(&mut self, slice: &[T]) -> Result<(), SgError>
+    where
+        T: Copy,
+    {
+        self.bst.try_extend_updates(slice.iter().map(|&e| (e, ())))
+    }
+
     /// Attempt conversion from an iterator.
     /// Will fail if iterator length exceeds `u16::MAX`.
     ///
@@ -330,6 +683,100 @@ impl<T: Ord + Default, const N: usize> SgSet<T, N> {
         }
     }
 
+    /// Attempt conversion from an iterator of any length.
+    /// Will fail if the number of unique elements exceeds the set's capacity.
+    ///
+    /// This is the recommended fallible builder: the [`FromIterator`] implementation (e.g. [`collect`][Iterator::collect])
+    /// panics on overflow, and [`try_from_iter`][crate::set::SgSet::try_from_iter] requires an [`ExactSizeIterator`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::{SgSet, SgError};
+    ///
+    /// let set = SgSet::<_, 3>::collect_checked(0..3);
+    /// assert!(set.is_ok());
+    ///
+    /// let set = SgSet::<_, 3>::collect_checked(0..4);
+    /// assert_eq!(set, Err(SgError::StackCapacityExceeded));
+    /// ```
+    pub fn collect_checked<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, SgError> {
+        Ok(SgSet {
+            bst: SgTree::collect_checked(iter.into_iter().map(|e| (e, ())))?,
+        })
+    }
+
+    /// Returns a clone of the set with a different capacity, `M`, leaving `self` unchanged.
+    /// The rebalance parameter and maximum height are carried over.
+    ///
+    /// Returns `Err` if the set has more than `M` elements, e.g. `M` is smaller than `N` and the set is too full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::{SgSet, SgError};
+    ///
+    /// let set: SgSet<i32, 10> = (0..4).collect();
+    ///
+    /// let smaller = set.try_clone_into::<4>().unwrap();
+    /// assert_eq!(smaller.capacity(), 4);
+    /// assert!(smaller.iter().eq(set.iter()));
+    ///
+    /// assert_eq!(set.try_clone_into::<3>(), Err(SgError::StackCapacityExceeded));
+    /// ```
+    pub fn try_clone_into<const M: usize>(&self) -> Result<SgSet<T, M>, SgError>
+    where
+        T: Clone,
+    {
+        if self.len() > M {
+            return Err(SgError::StackCapacityExceeded);
+        }
+
+        let mut set = SgSet::<T, M>::collect_checked(self.iter().cloned())?;
+
+        let (alpha_num, alpha_denom) = self.rebal_param();
+        set.set_rebal_param(alpha_num, alpha_denom)?;
+        set.set_max_height(self.max_height());
+
+        Ok(set)
+    }
+
+    /// Returns a pair of new sets, containing clones of the elements specified by the predicate and of the rest,
+    /// respectively, leaving `self` unchanged.
+    ///
+    /// Both sets always fit, since each holds at most `len()` elements and capacity `N` is shared.
+    /// The `Result` is kept for symmetry with other fallible APIs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgSet;
+    ///
+    /// let set: SgSet<i32, 10> = (1..=6).collect();
+    /// let (evens, odds) = set.try_partition(|x| x % 2 == 0).unwrap();
+    ///
+    /// assert!(evens.into_iter().eq([2, 4, 6]));
+    /// assert!(odds.into_iter().eq([1, 3, 5]));
+    /// assert_eq!(set.len(), 6);
+    /// ```
+    pub fn try_partition<F>(&self, mut pred: F) -> Result<(SgSet<T, N>, SgSet<T, N>), SgError>
+    where
+        T: Clone,
+        F: FnMut(&T) -> bool,
+    {
+        let mut matching = SgSet::new();
+        let mut non_matching = SgSet::new();
+
+        for elem in self.iter() {
+            match pred(elem) {
+                true => matching.try_insert(elem.clone())?,
+                false => non_matching.try_insert(elem.clone())?,
+            };
+        }
+
+        Ok((matching, non_matching))
+    }
+
     /// Gets an iterator that visits the values in the `SgSet` in ascending order.
     ///
     /// # Examples
@@ -448,6 +895,46 @@ impl<T: Ord + Default, const N: usize> SgSet<T, N> {
         removed
     }
 
+    /// Returns a reference to the value in the set that is equal to the given one, inserting it first if absent.
+    /// An existing value is kept, not replaced (unlike [`replace`][crate::set::SgSet::replace]), so repeated calls with
+    /// equal values return the same stored value, e.g. for interning.
+    ///
+    /// Returns `Err` if the value is absent and can't be inserted
+    /// (the set is full, or inserting would exceed the [maximum height][SgSet::set_max_height]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::{SgSet, SgError};
+    ///
+    /// let mut set = SgSet::<&str, 2>::new();
+    ///
+    /// let a = set.get_or_insert("a").unwrap() as *const &str;
+    /// assert_eq!(set.get_or_insert("a").unwrap() as *const &str, a);
+    /// assert_eq!(set.len(), 1);
+    ///
+    /// assert_eq!(set.get_or_insert("b"), Ok(&"b"));
+    /// assert_eq!(set.get_or_insert("c"), Err(SgError::StackCapacityExceeded));
+    /// ```
+    pub fn get_or_insert(&mut self, value: T) -> Result<&T, SgError>
+    where
+        T: Ord,
+    {
+        use crate::tree::node::NodeGetHelper;
+        use crate::tree::{Idx, SmallNode};
+
+        let ngh: NodeGetHelper<Idx> = self.bst.priv_get(None, &value);
+        let node_idx = match ngh.node_idx() {
+            Some(node_idx) => node_idx,
+            None => {
+                self.bst.check_growth(1)?;
+                self.bst.priv_balancing_insert::<Idx>(value, ()).1
+            }
+        };
+
+        Ok(self.bst.arena[node_idx].key())
+    }
+
     // TODO: add example
     /// Attempts to add a value to the set, replacing the existing value, if any, that is equal to the given
     /// one. Returns the replaced value.
@@ -493,57 +980,192 @@ impl<T: Ord + Default, const N: usize> SgSet<T, N> {
     /// ```
     /// use buggy_scapegoat::SgSet;
     ///
-    /// let xs = [1, 2, 3, 4, 5, 6];
-    /// let mut set: SgSet<i32, 10> = xs.iter().cloned().collect();
-    /// // Keep only the even numbers.
-    /// set.retain(|&k| k % 2 == 0);
-    /// assert!(set.iter().eq([2, 4, 6].iter()));
+    /// let xs = [1, 2, 3, 4, 5, 6];
+    /// let mut set: SgSet<i32, 10> = xs.iter().cloned().collect();
+    /// // Keep only the even numbers.
+    /// set.retain(|&k| k % 2 == 0);
+    /// assert!(set.iter().eq([2, 4, 6].iter()));
+    /// ```
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        T: Ord,
+        F: FnMut(&T) -> bool,
+    {
+        self.bst.retain(|k, _| f(k));
+    }
+
+    /// Retains only the elements within the range, removing all elements outside of it.
+    ///
+    /// The value may be any borrowed form of the set’s value type, but the ordering
+    /// on the borrowed form must match the ordering on the value type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgSet;
+    ///
+    /// let mut set: SgSet<i32, 10> = (1..=10).collect();
+    /// set.retain_range(3..=7);
+    /// assert!(set.iter().eq([3, 4, 5, 6, 7].iter()));
+    ///
+    /// // Full range, nothing removed
+    /// set.retain_range(..);
+    /// assert_eq!(set.len(), 5);
+    ///
+    /// // Empty range, everything removed
+    /// set.retain_range(5..5);
+    /// assert!(set.is_empty());
+    /// ```
+    pub fn retain_range<Q, R>(&mut self, range: R)
+    where
+        T: Borrow<Q> + Ord,
+        R: RangeBounds<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.bst.retain_range(range);
+    }
+
+    /// Returns a reference to the value in the set, if any, that is equal to the given value.
+    ///
+    /// The value may be any borrowed form of the set's value type,
+    /// but the ordering on the borrowed form *must* match the
+    /// ordering on the value type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgSet;
+    ///
+    /// let set: SgSet<_, 10> = [1, 2, 3].iter().cloned().collect();
+    /// assert_eq!(set.get(&2), Some(&2));
+    /// assert_eq!(set.get(&4), None);
+    /// ```
+    pub fn get<Q>(&self, value: &Q) -> Option<&T>
+    where
+        T: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.bst.get_key_value(value).map(|(k, _)| k)
+    }
+
+    /// Clears the set, removing all values.
+    /// The [maximum height][SgSet::set_max_height], if any, is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgSet;
+    ///
+    /// let mut v = SgSet::<_, 10>::new();
+    /// v.insert(1);
+    /// v.clear();
+    /// assert!(v.is_empty());;
+    /// ```
+    pub fn clear(&mut self) {
+        self.bst.clear()
+    }
+
+    /// Clears the set, removing all elements and resetting internal rebalance statistics.
+    /// Unlike [`clear`][crate::set::SgSet::clear], which preserves statistics to track performance over the set's lifetime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgSet;
+    ///
+    /// let mut v = SgSet::<_, 10>::new();
+    /// v.insert(1);
+    /// v.clear_stats();
+    /// assert!(v.is_empty());
+    /// ```
+    pub fn clear_stats(&mut self) {
+        self.bst.clear_stats()
+    }
+
+    /// Get occupancy statistics for the set's backing storage: live items, holes left by removals, and backing length.
+    /// Useful for quantifying fragmentation after heavy insert/remove churn.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgSet;
+    ///
+    /// let mut set: SgSet<usize, 10> = (0..4).collect();
+    /// set.remove(&3);
+    ///
+    /// let stats = set.arena_stats();
+    /// assert_eq!(stats.live, 3);
+    /// assert_eq!(stats.holes, stats.backing_len - stats.live);
+    /// ```
+    pub fn arena_stats(&self) -> ArenaStats {
+        self.bst.arena_stats()
+    }
+
+    /// Render the structure of the underlying tree in [Graphviz DOT](https://graphviz.org/doc/info/lang.html) format,
+    /// e.g. to observe rebalancing. Nodes are labeled by values, edges by left (`L`) or right (`R`) child.
+    /// Requires the `alloc` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgSet;
+    ///
+    /// let set = SgSet::<_, 10>::from_iter([2, 1, 3]);
+    /// let dot = set.to_dot();
+    ///
+    /// assert!(dot.starts_with("digraph SgTree {"));
+    /// assert!(dot.contains("[label=\"1\"]"));
+    /// assert_eq!(dot.matches(" -> ").count(), 2);
     /// ```
-    pub fn retain<F>(&mut self, mut f: F)
+    #[cfg(feature = "alloc")]
+    pub fn to_dot(&self) -> String
     where
-        T: Ord,
-        F: FnMut(&T) -> bool,
+        T: Debug,
     {
-        self.bst.retain(|k, _| f(k));
+        self.bst.to_dot()
     }
 
-    /// Returns a reference to the value in the set, if any, that is equal to the given value.
-    ///
-    /// The value may be any borrowed form of the set's value type,
-    /// but the ordering on the borrowed form *must* match the
-    /// ordering on the value type.
+    /// Rebuilds the set's tree into optimal balance, e.g. before a read-heavy phase when a high alpha was set.
+    /// Counts towards the set's rebalance statistics.
     ///
     /// # Examples
     ///
     /// ```
     /// use buggy_scapegoat::SgSet;
     ///
-    /// let set: SgSet<_, 10> = [1, 2, 3].iter().cloned().collect();
-    /// assert_eq!(set.get(&2), Some(&2));
-    /// assert_eq!(set.get(&4), None);
+    /// let mut set = SgSet::<_, 10>::new();
+    /// set.set_rebal_param(0.9, 1.0).unwrap();
+    /// set.extend(0..10);
+    ///
+    /// set.rebalance();
+    /// assert!(set.into_iter().eq(0..10));
     /// ```
-    pub fn get<Q>(&self, value: &Q) -> Option<&T>
-    where
-        T: Borrow<Q> + Ord,
-        Q: Ord + ?Sized,
-    {
-        self.bst.get_key_value(value).map(|(k, _)| k)
+    pub fn rebalance(&mut self) {
+        self.bst.rebalance()
     }
 
-    /// Clears the set, removing all values.
+    /// Compacts the set's backing storage and rebuilds its tree into optimal balance, e.g. after heavy churn.
+    /// The rebalance parameter set via [`set_rebal_param`][SgSet::set_rebal_param] is preserved.
+    /// Counts towards the set's rebalance statistics.
     ///
     /// # Examples
     ///
     /// ```
     /// use buggy_scapegoat::SgSet;
     ///
-    /// let mut v = SgSet::<_, 10>::new();
-    /// v.insert(1);
-    /// v.clear();
-    /// assert!(v.is_empty());;
-    /// ```
-    pub fn clear(&mut self) {
-        self.bst.clear()
+    /// let mut set = SgSet::<_, 10>::new();
+    /// set.set_rebal_param(0.9, 1.0).unwrap();
+    /// set.extend(0..10);
+    /// (0..5).for_each(|_| {
+    ///     set.pop_first();
+    /// });
+    ///
+    /// set.rebuild_balanced();
+    /// assert_eq!(set.rebal_param(), (0.9, 1.0));
+    /// assert!(set.into_iter().eq(5..10));
+    /// ```
+    pub fn rebuild_balanced(&mut self) {
+        self.bst.rebuild_balanced()
     }
 
     /// Returns `true` if the set contains a value.
@@ -569,6 +1191,59 @@ impl<T: Ord + Default, const N: usize> SgSet<T, N> {
         self.bst.contains_key(value)
     }
 
+    /// Returns the 0-based position of a value in the set's sorted order, or `None` if the value is absent.
+    ///
+    /// The value may be any borrowed form of the set's value type,
+    /// but the ordering on the borrowed form *must* match the ordering on the value type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgSet;
+    ///
+    /// let set = SgSet::<_, 10>::from_iter(["b", "c", "a"]);
+    /// assert_eq!(set.rank("a"), Some(0));
+    /// assert_eq!(set.rank("c"), Some(2));
+    /// assert_eq!(set.rank("d"), None);
+    /// ```
+    ///
+    /// ### Note
+    ///
+    /// Rank is computed by an `O(n)` in-order traversal.
+    /// The inverse of [`nth_smallest`][crate::set::SgSet::nth_smallest].
+    pub fn rank<Q>(&self, value: &Q) -> Option<usize>
+    where
+        T: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        self.bst.rank(value)
+    }
+
+    /// Returns `true` if the set contains every value yielded by `iter`, which must be strictly ascending.
+    /// Checked in a single ordered pass over the set, instead of a [`contains`][crate::set::SgSet::contains] lookup per value.
+    /// Returns `false` if `iter` isn't strictly ascending.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgSet;
+    ///
+    /// let set: SgSet<_, 10> = (1..=9).collect();
+    /// assert!(set.contains_all_sorted([2, 3, 5, 7].into_iter()));
+    /// assert!(!set.contains_all_sorted([2, 4, 10].into_iter()));
+    /// ```
+    pub fn contains_all_sorted<I: Iterator<Item = T>>(&self, mut iter: I) -> bool
+    where
+        T: Ord,
+    {
+        let mut set_iter = self.iter();
+        iter.all(|value| {
+            set_iter
+                .find(|set_value| **set_value >= value)
+                .is_some_and(|set_value| *set_value == value)
+        })
+    }
+
     /// Returns a reference to the first/minium value in the set, if any.
     ///
     /// # Examples
@@ -590,6 +1265,25 @@ impl<T: Ord + Default, const N: usize> SgSet<T, N> {
         self.bst.first_key()
     }
 
+    /// Returns a copy of the first/minimum value in the set, if any.
+    /// A by-value alternative to [`first`][SgSet::first] for `Copy` types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgSet;
+    ///
+    /// let set = SgSet::<_, 10>::from_iter([3, 1, 2]);
+    /// assert_eq!(set.first_copied(), Some(1));
+    /// assert_eq!(SgSet::<u8, 10>::new().first_copied(), None);
+    /// ```
+    pub fn first_copied(&self) -> Option<T>
+    where
+        T: Ord + Copy,
+    {
+        self.first().copied()
+    }
+
     /// Removes the first value from the set and returns it, if any.
     /// The first value is the minimum value that was in the set.
     ///
@@ -634,6 +1328,25 @@ impl<T: Ord + Default, const N: usize> SgSet<T, N> {
         self.bst.last_key()
     }
 
+    /// Returns a copy of the last/maximum value in the set, if any.
+    /// A by-value alternative to [`last`][SgSet::last] for `Copy` types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgSet;
+    ///
+    /// let set = SgSet::<_, 10>::from_iter([3, 1, 2]);
+    /// assert_eq!(set.last_copied(), Some(3));
+    /// assert_eq!(SgSet::<u8, 10>::new().last_copied(), None);
+    /// ```
+    pub fn last_copied(&self) -> Option<T>
+    where
+        T: Ord + Copy,
+    {
+        self.last().copied()
+    }
+
     /// Removes the last value from the set and returns it, if any.
     /// The last value is the maximum value that was in the set.
     ///
@@ -657,6 +1370,62 @@ impl<T: Ord + Default, const N: usize> SgSet<T, N> {
         self.bst.pop_last().map(|(k, _)| k)
     }
 
+    /// Returns a reference to the value at 0-based position `n` in sorted order, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgSet;
+    ///
+    /// let set = SgSet::<_, 10>::from_iter([30, 10, 20]);
+    /// assert_eq!(set.nth_smallest(0), Some(&10));
+    /// assert_eq!(set.nth_smallest(2), Some(&30));
+    /// assert_eq!(set.nth_smallest(3), None);
+    /// ```
+    ///
+    /// ### Note
+    ///
+    /// Selection is computed by an `O(n)` in-order traversal.
+    pub fn nth_smallest(&self, n: usize) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.iter().nth(n)
+    }
+
+    /// Returns a reference to the value at percentile `p` (in range `0.0..=1.0`), if any.
+    /// Uses the nearest-rank method: the smallest value such that at least `p` of the set is less than or equal to it.
+    /// So `0.0` is the minimum, `1.0` is the maximum, and `0.5` is the median (the lower median, for even-sized sets).
+    /// Returns `None` if the set is empty or `p` is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgSet;
+    ///
+    /// let set = SgSet::<_, 10>::from_iter([1, 2, 3, 4, 5]);
+    /// assert_eq!(set.percentile(0.0), Some(&1));
+    /// assert_eq!(set.percentile(0.5), Some(&3));
+    /// assert_eq!(set.percentile(0.9), Some(&5));
+    /// assert_eq!(set.percentile(1.0), Some(&5));
+    /// assert_eq!(set.percentile(1.5), None);
+    /// ```
+    ///
+    /// ### Note
+    ///
+    /// Like [`nth_smallest`][crate::set::SgSet::nth_smallest], this is an `O(n)` in-order traversal.
+    pub fn percentile(&self, p: f32) -> Option<&T>
+    where
+        T: Ord,
+    {
+        if self.is_empty() || !(0.0..=1.0).contains(&p) {
+            return None;
+        }
+
+        let rank = ((p * self.len() as f32).ceil() as usize).clamp(1, self.len());
+        self.nth_smallest(rank - 1)
+    }
+
     /// Returns the number of elements in the set.
     ///
     /// # Examples
@@ -787,6 +1556,62 @@ impl<T: Ord + Default, const N: usize> SgSet<T, N> {
         Union::new(self, other)
     }
 
+    /// Returns the number of values in `self` or `other`, without duplicates.
+    /// Equivalent to `self.union(other).count()`, but computed lazily via an in-order merge in `O(n + m)` time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgSet;
+    ///
+    /// let a = SgSet::<_, 10>::from_iter([1, 2, 3]);
+    /// let b = SgSet::<_, 10>::from_iter([3, 4]);
+    /// let c = SgSet::<_, 10>::from_iter([5, 6]);
+    ///
+    /// // Overlapping
+    /// assert_eq!(a.union_count(&b), 4);
+    /// assert_eq!(a.union_count(&b), a.union(&b).count());
+    ///
+    /// // Disjoint
+    /// assert_eq!(a.union_count(&c), 5);
+    /// assert_eq!(a.union_count(&c), a.union(&c).count());
+    /// ```
+    pub fn union_count(&self, other: &SgSet<T, N>) -> usize
+    where
+        T: Ord,
+    {
+        let (self_only_cnt, common_cnt, other_only_cnt) = self.merge_cnts(other);
+        self_only_cnt + common_cnt + other_only_cnt
+    }
+
+    /// Returns the number of values in `self` or `other`, but not in both.
+    /// Equivalent to `self.symmetric_difference(other).count()`, but computed lazily via an in-order merge in `O(n + m)` time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgSet;
+    ///
+    /// let a = SgSet::<_, 10>::from_iter([1, 2, 3]);
+    /// let b = SgSet::<_, 10>::from_iter([3, 4]);
+    /// let c = SgSet::<_, 10>::from_iter([5, 6]);
+    ///
+    /// // Overlapping
+    /// assert_eq!(a.symmetric_difference_count(&b), 3);
+    /// assert_eq!(a.symmetric_difference_count(&b), a.symmetric_difference(&b).count());
+    ///
+    /// // Disjoint
+    /// assert_eq!(a.symmetric_difference_count(&c), 5);
+    /// assert_eq!(a.symmetric_difference_count(&c), a.symmetric_difference(&c).count());
+    /// ```
+    pub fn symmetric_difference_count(&self, other: &SgSet<T, N>) -> usize
+    where
+        T: Ord,
+    {
+        let (self_only_cnt, _, other_only_cnt) = self.merge_cnts(other);
+        self_only_cnt + other_only_cnt
+    }
+
     /// Returns `true` if the set contains no elements.
     ///
     /// # Examples
@@ -890,6 +1715,41 @@ impl<T: Ord + Default, const N: usize> SgSet<T, N> {
     {
         other.is_subset(self)
     }
+
+    // Private API -----------------------------------------------------------------------------------------------------
+
+    // Merge in-order traversals, counting values only in `self`, in both, and only in `other`.
+    fn merge_cnts(&self, other: &SgSet<T, N>) -> (usize, usize, usize) {
+        let (mut self_only_cnt, mut common_cnt, mut other_only_cnt) = (0, 0, 0);
+        let mut self_iter = self.iter();
+        let mut other_iter = other.iter();
+        let mut opt_self = self_iter.next();
+        let mut opt_other = other_iter.next();
+
+        while let (Some(self_val), Some(other_val)) = (opt_self, opt_other) {
+            match self_val.cmp(other_val) {
+                Ordering::Less => {
+                    self_only_cnt += 1;
+                    opt_self = self_iter.next();
+                }
+                Ordering::Equal => {
+                    common_cnt += 1;
+                    opt_self = self_iter.next();
+                    opt_other = other_iter.next();
+                }
+                Ordering::Greater => {
+                    other_only_cnt += 1;
+                    opt_other = other_iter.next();
+                }
+            }
+        }
+
+        // At most one side has a remainder
+        self_only_cnt += opt_self.map_or(0, |_| 1 + self_iter.len());
+        other_only_cnt += opt_other.map_or(0, |_| 1 + other_iter.len());
+
+        (self_only_cnt, common_cnt, other_only_cnt)
+    }
 }
 
 // Convenience Traits --------------------------------------------------------------------------------------------------
@@ -906,6 +1766,41 @@ where
     }
 }
 
+// Arbitrary, for property testing. Stops generating at capacity.
+#[cfg(feature = "arbitrary")]
+impl<'a, T, const N: usize> arbitrary::Arbitrary<'a> for SgSet<T, N>
+where
+    T: Ord + Default + arbitrary::Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut set = SgSet::new();
+        for value in u.arbitrary_iter::<T>()? {
+            if set.try_insert(value?).is_err() {
+                break;
+            }
+        }
+
+        Ok(set)
+    }
+}
+
+// Display
+impl<T, const N: usize> fmt::Display for SgSet<T, N>
+where
+    T: Ord + Default + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{")?;
+        for (i, (k, _)) in self.bst.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", k)?;
+        }
+        write!(f, "}}")
+    }
+}
+
 // From array.
 impl<T, const N: usize> From<[T; N]> for SgSet<T, N>
 where
@@ -932,7 +1827,9 @@ where
     }
 }
 
-// Construct from iterator.
+/// Construct from iterator.
+///
+/// Panics if capacity is exceeded, use [`collect_checked`][crate::set::SgSet::collect_checked] for a fallible alternative.
 impl<T, const N: usize> FromIterator<T> for SgSet<T, N>
 where
     T: Ord + Default,
@@ -1075,3 +1972,342 @@ impl<T: Ord + Default + Clone, const N: usize> BitXor<&SgSet<T, N>> for &SgSet<T
         self.symmetric_difference(rhs).cloned().collect()
     }
 }
+
+// Test ----------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::{SgError, SgSet};
+
+    #[test]
+    fn test_collect_checked() {
+        const CAPACITY: usize = 10;
+
+        // Fits, duplicates don't consume capacity
+        let set =
+            SgSet::<usize, CAPACITY>::collect_checked((0..CAPACITY).chain(0..CAPACITY)).unwrap();
+        assert!(set.iter().copied().eq(0..CAPACITY));
+
+        // Overflows
+        assert_eq!(
+            SgSet::<usize, CAPACITY>::collect_checked(0..=CAPACITY),
+            Err(SgError::StackCapacityExceeded)
+        );
+    }
+
+    #[test]
+    fn test_try_insert_sorted() {
+        const CAPACITY: usize = 128;
+
+        // Sorted input: a single rebuild, vs. many for regular insertion
+        let mut sorted_set = SgSet::<usize, CAPACITY>::new();
+        sorted_set.try_insert_sorted(0..CAPACITY).unwrap();
+        sorted_set.bst.assert_invariants();
+        assert!(sorted_set.iter().copied().eq(0..CAPACITY));
+
+        let mut extended_set = SgSet::<usize, CAPACITY>::new();
+        extended_set.extend(0..CAPACITY);
+        assert_eq!(sorted_set.bst.rebal_cnt(), 1);
+        assert!(extended_set.bst.rebal_cnt() > 1);
+
+        // Shuffled input, with duplicates: correct contents
+        let shuffled: Vec<usize> = (0..CAPACITY).map(|i| (i * 37) % 64).collect();
+        let mut shuffled_set = SgSet::<usize, CAPACITY>::new();
+        shuffled_set
+            .try_insert_sorted(shuffled.into_iter())
+            .unwrap();
+        shuffled_set.bst.assert_invariants();
+        assert!(shuffled_set.iter().copied().eq(0..64));
+
+        // Appending to a non-empty set
+        shuffled_set.try_insert_sorted(60..70).unwrap();
+        shuffled_set.bst.assert_invariants();
+        assert!(shuffled_set.iter().copied().eq(0..70));
+    }
+
+    #[test]
+    fn test_contains_all_sorted() {
+        let set: SgSet<usize, 32> = (0..32).filter(|x| x % 3 == 0).collect();
+
+        // Valid ascending subsets
+        assert!(set.contains_all_sorted([0, 9, 12, 30].into_iter()));
+        assert!(set.contains_all_sorted(set.iter().copied()));
+        assert!(set.contains_all_sorted(core::iter::empty()));
+
+        // Absent element at the start, middle, and past the end
+        assert!(!set.contains_all_sorted([1, 3].into_iter()));
+        assert!(!set.contains_all_sorted([3, 6, 7, 9].into_iter()));
+        assert!(!set.contains_all_sorted([27, 30, 33].into_iter()));
+
+        // Not strictly ascending
+        assert!(!set.contains_all_sorted([6, 3].into_iter()));
+        assert!(!set.contains_all_sorted([6, 6].into_iter()));
+    }
+
+    #[test]
+    fn test_saturating_extend() {
+        const CAPACITY: usize = 16;
+        let mut set = SgSet::<usize, CAPACITY>::new();
+
+        assert_eq!(set.saturating_extend((0..(CAPACITY * 2)).rev()), CAPACITY);
+        assert!(set.is_full());
+        assert!(set.iter().copied().eq(CAPACITY..(CAPACITY * 2)));
+
+        assert_eq!(set.saturating_extend(0..CAPACITY), 0);
+        assert_eq!(set.len(), CAPACITY);
+
+        // Already present values aren't counted, iteration stops at the first rejection
+        set.clear();
+        let mut iter = (0..4).chain(0..(CAPACITY * 2));
+        assert_eq!(set.saturating_extend(iter.by_ref()), CAPACITY);
+        assert!(set.iter().copied().eq(0..CAPACITY));
+        assert!(iter.eq((CAPACITY + 1)..(CAPACITY * 2)));
+
+        // Height-bounded
+        let mut set = SgSet::<usize, CAPACITY>::new();
+        set.set_max_height(Some(2));
+        assert_eq!(set.saturating_extend(0..), 3);
+        assert_eq!(set.len(), 3);
+    }
+
+    #[test]
+    fn test_display() {
+        let mut set = SgSet::<char, 10>::new();
+        assert_eq!(set.to_string(), "{}");
+
+        set.insert('b');
+        assert_eq!(set.to_string(), "{b}");
+
+        set.insert('c');
+        set.insert('a');
+        assert_eq!(set.to_string(), "{a, b, c}");
+    }
+
+    #[test]
+    fn test_max_capacity_const() {
+        const CAPACITY: usize = SgSet::<u8, 12>::max_capacity_const();
+        const _: () = assert!(CAPACITY == 12);
+
+        let set = SgSet::<u8, CAPACITY>::new();
+        let buf = [0_u8; SgSet::<u8, CAPACITY>::max_capacity_const()];
+        assert_eq!(buf.len(), set.capacity());
+    }
+
+    #[test]
+    #[cfg(feature = "arbitrary")]
+    fn test_arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        const CAPACITY: usize = 8;
+        let bytes: Vec<u8> = (0..=255).cycle().take(4096).collect();
+        let mut u = Unstructured::new(&bytes);
+
+        while !u.is_empty() {
+            let set = SgSet::<u8, CAPACITY>::arbitrary(&mut u).unwrap();
+            assert!(set.len() <= CAPACITY);
+            set.bst.assert_invariants();
+        }
+    }
+
+    #[test]
+    fn test_try_clone_into() {
+        const CAPACITY: usize = 16;
+        let mut set: SgSet<usize, CAPACITY> = (0..8).collect();
+        set.set_max_height(Some(4));
+
+        let larger = set.try_clone_into::<{ CAPACITY * 2 }>().unwrap();
+        assert_eq!(larger.capacity(), CAPACITY * 2);
+        assert!(larger.iter().eq(set.iter()));
+        assert_eq!(larger.max_height(), Some(4));
+
+        let smaller = set.try_clone_into::<8>().unwrap();
+        assert_eq!(smaller.capacity(), 8);
+        assert!(smaller.iter().eq(set.iter()));
+
+        assert_eq!(
+            set.try_clone_into::<7>(),
+            Err(SgError::StackCapacityExceeded)
+        );
+        assert_eq!(set.len(), 8);
+    }
+
+    #[test]
+    fn test_try_partition() {
+        const CAPACITY: usize = 10;
+        let set: SgSet<usize, CAPACITY> = (1..=10).collect();
+
+        let (evens, odds) = set.try_partition(|x| x % 2 == 0).unwrap();
+        assert!(evens.iter().copied().eq([2, 4, 6, 8, 10]));
+        assert!(odds.iter().copied().eq([1, 3, 5, 7, 9]));
+        assert!(evens.is_disjoint(&odds));
+
+        // Original untouched
+        assert_eq!(set.len(), CAPACITY);
+
+        // Edge cases, all in one set
+        let (all, none) = set.try_partition(|_| true).unwrap();
+        assert_eq!((all, none.len()), (set.clone(), 0));
+        let (none, all) = set.try_partition(|_| false).unwrap();
+        assert_eq!((none.len(), all), (0, set));
+    }
+
+    #[test]
+    fn test_get_or_insert() {
+        use core::ptr;
+
+        // Equal, but distinguishable by capacity
+        const CAPACITY: usize = 4;
+        let mut set = SgSet::<Vec<u8>, CAPACITY>::new();
+
+        let interned: *const Vec<u8> = set.get_or_insert(Vec::with_capacity(8)).unwrap();
+        for _ in 0..CAPACITY {
+            let again = set.get_or_insert(Vec::new()).unwrap();
+            assert!(ptr::eq(again, interned));
+            assert_eq!(again.capacity(), 8); // Stored value kept, not replaced
+        }
+        assert_eq!(set.len(), 1);
+
+        // Interleaved with other values, stored element doesn't move
+        for i in 1..CAPACITY as u8 {
+            assert_eq!(set.get_or_insert(vec![i]), Ok(&vec![i]));
+        }
+        assert!(set.is_full());
+        assert!(ptr::eq(set.get_or_insert(Vec::new()).unwrap(), interned));
+        assert_eq!(
+            set.get_or_insert(vec![CAPACITY as u8]),
+            Err(SgError::StackCapacityExceeded)
+        );
+        assert_eq!(set.len(), CAPACITY);
+
+        // Height-bounded, absent values rejected without panic
+        let mut set = SgSet::<u8, CAPACITY>::new();
+        set.set_max_height(Some(2));
+        for i in 0..3 {
+            assert_eq!(set.get_or_insert(i), Ok(&i));
+        }
+        assert_eq!(set.get_or_insert(3), Err(SgError::MaximumHeightExceeded));
+        assert_eq!(set.get_or_insert(1), Ok(&1));
+        assert_eq!(set.len(), 3);
+    }
+
+    #[test]
+    fn test_nth_smallest_percentile() {
+        // Odd-sized, median is middle value
+        let odd = SgSet::<_, 10>::from_iter([50, 10, 40, 20, 30]);
+        assert_eq!(odd.nth_smallest(0), Some(&10));
+        assert_eq!(odd.nth_smallest(2), Some(&30));
+        assert_eq!(odd.nth_smallest(4), Some(&50));
+        assert_eq!(odd.nth_smallest(5), None);
+        assert_eq!(odd.percentile(0.5), Some(&30));
+
+        // Even-sized, median is lower middle value
+        let even = SgSet::<_, 10>::from_iter([40, 10, 30, 20]);
+        assert_eq!(even.percentile(0.5), Some(&20));
+        assert_eq!(even.percentile(0.51), Some(&30));
+
+        // Boundaries
+        assert_eq!(even.percentile(0.0), Some(&10));
+        assert_eq!(even.percentile(0.25), Some(&10));
+        assert_eq!(even.percentile(0.26), Some(&20));
+        assert_eq!(even.percentile(1.0), Some(&40));
+        assert_eq!(even.percentile(-0.1), None);
+        assert_eq!(even.percentile(1.1), None);
+        assert_eq!(even.percentile(f32::NAN), None);
+
+        // Single and empty
+        let single = SgSet::<_, 10>::from_iter([7]);
+        assert_eq!(single.percentile(0.0), Some(&7));
+        assert_eq!(single.percentile(1.0), Some(&7));
+        assert_eq!(SgSet::<usize, 10>::new().nth_smallest(0), None);
+        assert_eq!(SgSet::<usize, 10>::new().percentile(0.5), None);
+    }
+
+    #[test]
+    fn test_rank() {
+        let set = SgSet::<_, 10>::from_iter([4, 1, 3, 2, 5]);
+
+        // First, middle, last
+        assert_eq!(set.rank(&1), Some(0));
+        assert_eq!(set.rank(&3), Some(2));
+        assert_eq!(set.rank(&5), Some(4));
+
+        // Absent
+        assert_eq!(set.rank(&0), None);
+        assert_eq!(set.rank(&6), None);
+
+        // Inverse of `nth_smallest`
+        for i in 0..set.len() {
+            assert_eq!(set.rank(set.nth_smallest(i).unwrap()), Some(i));
+        }
+
+        assert_eq!(SgSet::<usize, 10>::new().rank(&0), None);
+    }
+
+    #[test]
+    fn test_empty_static() {
+        use std::sync::Mutex;
+
+        static SET: Mutex<SgSet<usize, 8>> = Mutex::new(SgSet::EMPTY);
+
+        assert!(SET.lock().unwrap().is_empty());
+        assert_eq!(*SET.lock().unwrap(), SgSet::new());
+
+        // Behaves like a runtime-constructed set
+        SET.lock().unwrap().extend([3, 1, 2]);
+        assert!(SET.lock().unwrap().iter().eq(&[1, 2, 3]));
+        assert_eq!(SET.lock().unwrap().capacity(), 8);
+        assert_eq!(SET.lock().unwrap().pop_first(), Some(1));
+        assert!(SET.lock().unwrap().iter().eq(&[2, 3]));
+    }
+
+    #[test]
+    fn test_try_merge_sorted() {
+        const CAPACITY: usize = 64;
+        let a: SgSet<usize, CAPACITY> = (0..40).filter(|i| i % 2 == 0).collect();
+        let b: SgSet<usize, CAPACITY> = (0..40).filter(|i| i % 3 == 0).collect();
+
+        // Same contents as `|`, with fewer rebalances
+        let merged = SgSet::try_merge_sorted(&a, &b).unwrap();
+        let or_merged = &a | &b;
+        assert_eq!(merged, or_merged);
+        assert_eq!(merged.len(), a.union_count(&b));
+        assert_eq!(merged.bst.rebal_cnt(), 1);
+        assert!(merged.bst.rebal_cnt() < or_merged.bst.rebal_cnt());
+
+        // Symmetric
+        assert_eq!(SgSet::try_merge_sorted(&b, &a).unwrap(), merged);
+
+        // Empty operands
+        let empty = SgSet::<usize, CAPACITY>::new();
+        assert_eq!(SgSet::try_merge_sorted(&a, &empty).unwrap(), a);
+        assert_eq!(SgSet::try_merge_sorted(&empty, &empty).unwrap(), empty);
+
+        // Union exceeds capacity, even though each operand fits
+        let low: SgSet<usize, CAPACITY> = (0..CAPACITY).collect();
+        let high: SgSet<usize, CAPACITY> = (1..=CAPACITY).collect();
+        assert_eq!(
+            SgSet::try_merge_sorted(&low, &high),
+            Err(SgError::StackCapacityExceeded)
+        );
+    }
+
+    #[test]
+    fn test_first_last_copied() {
+        const CAPACITY: usize = 16;
+        let mut set: SgSet<i32, CAPACITY> = SgSet::new();
+        assert_eq!((set.first_copied(), set.last_copied()), (None, None));
+
+        set.extend([7, -3, 12, 0, 5]);
+        assert_eq!(set.first_copied(), set.first().copied());
+        assert_eq!(set.last_copied(), set.last().copied());
+        assert_eq!(
+            (set.first_copied(), set.last_copied()),
+            (Some(-3), Some(12))
+        );
+
+        // Tracks changes to the extremes
+        set.pop_first();
+        set.insert(20);
+        assert_eq!((set.first_copied(), set.last_copied()), (Some(0), Some(20)));
+    }
+}
@@ -12,6 +12,11 @@ use tinyvec::{ArrayVec, ArrayVecIterator};
 ///
 /// This `struct` is created by the [`iter`][crate::set::SgSet::iter] method on [`SgSet`][crate::set::SgSet].
 /// See its documentation for more.
+///
+/// The iterator holds a shared borrow of the set, so it stays valid across other `&self` calls (e.g. lookups or
+/// other iterators), none of which modify the set.
+/// Methods taking `&mut self`, including those that reorder backing storage (e.g. [`retain`][crate::set::SgSet::retain]),
+/// can't be called until the iterator is dropped.
 pub struct Iter<'a, T: Ord + Default, const N: usize> {
     ref_iter: TreeIter<'a, T, (), N>,
 }
@@ -31,6 +36,11 @@ impl<'a, T: Ord + Default, const N: usize> Iterator for Iter<'a, T, N> {
     fn next(&mut self) -> Option<Self::Item> {
         self.ref_iter.next().map(|(k, _)| k)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
 }
 
 impl<'a, T: Ord + Default, const N: usize> ExactSizeIterator for Iter<'a, T, N> {
@@ -39,6 +49,14 @@ impl<'a, T: Ord + Default, const N: usize> ExactSizeIterator for Iter<'a, T, N>
     }
 }
 
+impl<'a, T: Ord + Default, const N: usize> Clone for Iter<'a, T, N> {
+    fn clone(&self) -> Self {
+        Iter {
+            ref_iter: self.ref_iter.clone(),
+        }
+    }
+}
+
 /// An owning iterator over the items of a [`SgSet`][crate::set::SgSet].
 ///
 /// This `struct` is created by the [`into_iter`][crate::set::SgSet::into_iter] method on [`SgSet`][crate::set::SgSet]
@@ -62,6 +80,17 @@ impl<T: Ord + Default, const N: usize> Iterator for IntoIter<T, N> {
     fn next(&mut self) -> Option<Self::Item> {
         self.cons_iter.next().map(|(k, _)| k)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T: Ord + Default, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.cons_iter.next_back().map(|(k, _)| k)
+    }
 }
 
 impl<T: Ord + Default, const N: usize> ExactSizeIterator for IntoIter<T, N> {
@@ -155,6 +184,11 @@ impl<'a, T: Ord + Default, const N: usize> Iterator for Intersection<'a, T, N> {
             None => None,
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
 }
 
 impl<'a, T: Ord + Default, const N: usize> ExactSizeIterator for Intersection<'a, T, N> {
@@ -218,6 +252,11 @@ impl<'a, T: Ord + Default, const N: usize> Iterator for Difference<'a, T, N> {
             None => None,
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
 }
 
 impl<'a, T: Ord + Default, const N: usize> ExactSizeIterator for Difference<'a, T, N> {
@@ -305,6 +344,11 @@ impl<'a, T: Ord + Default, const N: usize> Iterator for SymmetricDifference<'a,
             None => None,
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
 }
 
 impl<'a, T: Ord + Default, const N: usize> ExactSizeIterator for SymmetricDifference<'a, T, N> {
@@ -390,6 +434,11 @@ impl<'a, T: Ord + Default, const N: usize> Iterator for Union<'a, T, N> {
             None => None,
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
 }
 
 impl<'a, T: Ord + Default, const N: usize> ExactSizeIterator for Union<'a, T, N> {
@@ -398,3 +447,110 @@ impl<'a, T: Ord + Default, const N: usize> ExactSizeIterator for Union<'a, T, N>
         self.total_cnt - self.spent_cnt
     }
 }
+
+// Builder -------------------------------------------------------------------------------------------------------------
+
+/// An incremental builder for a [`SgSet`][crate::set::SgSet], for values arriving one at a time.
+/// Once the set is full, new values are rejected and handed back to the caller.
+///
+/// This `struct` is created by the [`builder`][crate::set::SgSet::builder] method on [`SgSet`][crate::set::SgSet].
+pub struct SetBuilder<T: Ord + Default, const N: usize> {
+    pub(super) set: SgSet<T, N>,
+}
+
+impl<T: Ord + Default, const N: usize> SetBuilder<T, N> {
+    /// Adds a value, a no-op if the value was already pushed.
+    /// If the set is full and the value is new, returns ownership of the rejected value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use buggy_scapegoat::SgSet;
+    ///
+    /// let mut builder = SgSet::<_, 1>::builder();
+    /// assert_eq!(builder.push(1), Ok(()));
+    /// assert_eq!(builder.push(1), Ok(()));
+    /// assert_eq!(builder.push(2), Err(2));
+    /// ```
+    pub fn push(&mut self, value: T) -> Result<(), T> {
+        if self.set.is_full() && !self.set.contains(&value) {
+            return Err(value);
+        }
+
+        self.set.insert(value);
+        Ok(())
+    }
+
+    /// Returns `true` if pushing a new value would be rejected.
+    pub fn is_full(&self) -> bool {
+        self.set.is_full()
+    }
+
+    /// Finishes building, returning the set.
+    pub fn finish(self) -> SgSet<T, N> {
+        self.set
+    }
+}
+
+// Test ----------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::set::SgSet;
+
+    const CAPACITY: usize = 16;
+
+    fn assert_exact_size_hint<I: ExactSizeIterator>(mut iter: I, expected_len: usize) {
+        assert_eq!(iter.size_hint(), (expected_len, Some(expected_len)));
+
+        iter.next();
+        assert_eq!(iter.size_hint(), (expected_len - 1, Some(expected_len - 1)));
+        assert_eq!(iter.len(), expected_len - 1);
+
+        iter.by_ref().for_each(drop);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    fn test_iter_clone() {
+        let set: SgSet<usize, CAPACITY> = (0..6).collect();
+
+        let mut iter = set.iter();
+        iter.nth(2);
+        let mut iter_clone = iter.clone();
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter_clone.next(), Some(&3));
+        assert_eq!(iter.next(), Some(&4));
+        assert!(iter_clone.copied().eq([4, 5]));
+        assert!(iter.copied().eq([5]));
+    }
+
+    #[test]
+    fn test_size_hint() {
+        let a: SgSet<usize, CAPACITY> = (0..6).collect();
+        let b: SgSet<usize, CAPACITY> = (4..8).collect();
+
+        assert_exact_size_hint(a.iter(), 6);
+        assert_exact_size_hint(a.intersection(&b), 2);
+        assert_exact_size_hint(a.difference(&b), 4);
+        assert_exact_size_hint(a.symmetric_difference(&b), 6);
+        assert_exact_size_hint(a.union(&b), 8);
+        assert_exact_size_hint(a.into_iter(), 6);
+    }
+
+    #[test]
+    fn test_set_builder() {
+        let mut builder = SgSet::<usize, CAPACITY>::builder();
+
+        let rejected: Vec<usize> = (0..(CAPACITY * 2))
+            .filter_map(|i| builder.push(i).err())
+            .collect();
+
+        assert!(builder.is_full());
+        assert_eq!(builder.push(0), Ok(()));
+        assert!(rejected.into_iter().eq(CAPACITY..(CAPACITY * 2)));
+
+        let set = builder.finish();
+        assert!(set.into_iter().eq(0..CAPACITY));
+    }
+}
@@ -1,8 +1,10 @@
+use core::iter::FilterMap;
 use core::ops::{Index, IndexMut};
 use core::slice::{Iter, IterMut};
 
 use super::node::{Node, NodeGetHelper, NodeSwapHistHelper};
 use super::node_dispatch::SmallNode;
+use super::Idx;
 
 use smallnum::SmallUnsigned;
 use tinyvec::ArrayVec;
@@ -15,6 +17,31 @@ All members in subset are <= host pointer width in size.
 If caller obeys contract, `U` will be smallest unsigned capable of representing const `N` (e.g. static capacity).
 */
 
+/// Iterator over occupied arena slots, see [`Arena::iter_occupied`].
+pub type OccupiedIter<'a, K, V, U> = FilterMap<
+    Iter<'a, Option<Node<K, V, U>>>,
+    fn(&Option<Node<K, V, U>>) -> Option<&Node<K, V, U>>,
+>;
+
+/// Mutable iterator over occupied arena slots, see [`Arena::iter_occupied_mut`].
+pub type OccupiedIterMut<'a, K, V, U> = FilterMap<
+    IterMut<'a, Option<Node<K, V, U>>>,
+    fn(&mut Option<Node<K, V, U>>) -> Option<&mut Node<K, V, U>>,
+>;
+
+/// Arena occupancy statistics, e.g. to quantify fragmentation left behind by removals.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ArenaStats {
+    /// Number of occupied slots, e.g. live nodes.
+    pub live: usize,
+
+    /// Number of empty slots (holes) left behind by removals, pending reuse.
+    pub holes: usize,
+
+    /// Length of the backing storage, occupied or not.
+    pub backing_len: usize,
+}
+
 /// An arena allocator, meta programmable for low memory footprint.
 #[derive(Clone, Debug)]
 pub struct Arena<K: Default, V: Default, U: Default, const N: usize> {
@@ -56,14 +83,28 @@ impl<
 
         a
     }
-    /// Returns an iterator over immutable arena elements.
-    pub fn iter(&self) -> Iter<'_, Option<Node<K, V, U>>> {
-        self.vec.iter()
+    /// Returns an iterator over occupied arena elements, skipping empty slots.
+    pub fn iter_occupied(&self) -> OccupiedIter<'_, K, V, U> {
+        self.vec.iter().filter_map(Option::as_ref)
     }
 
-    /// Returns an iterator over arena elements that allows modifying each value.
-    pub fn iter_mut(&mut self) -> IterMut<'_, Option<Node<K, V, U>>> {
-        self.vec.iter_mut()
+    /// Returns an iterator over occupied arena elements that allows modifying each node, skipping empty slots.
+    pub fn iter_occupied_mut(&mut self) -> OccupiedIterMut<'_, K, V, U> {
+        self.vec.iter_mut().filter_map(Option::as_mut)
+    }
+
+    /// Returns a pair of mutable iterators over occupied nodes, split at a backing storage index.
+    /// `mid` must be the number of keys below the split point, after `SgTree::sort_arena`
+    /// packed nodes into the front of the arena in key order.
+    pub fn split_occupied_mut(
+        &mut self,
+        mid: usize,
+    ) -> (OccupiedIterMut<'_, K, V, U>, OccupiedIterMut<'_, K, V, U>) {
+        let (lo, hi) = self.vec.split_at_mut(mid);
+        (
+            lo.iter_mut().filter_map(Option::as_mut),
+            hi.iter_mut().filter_map(Option::as_mut),
+        )
     }
 
     /// Total capacity, e.g. maximum number of items.
@@ -184,11 +225,44 @@ impl<
         swap_history.curr_idx(root_idx)
     }
 
+    /// Remove empty slots by shifting occupied nodes down, preserving their relative order.
+    /// Returns a remap where `remap[old_idx]` is the new index of the node previously at `old_idx`
+    /// (entries for previously empty slots are meaningless). Caller must fix up child indexes accordingly.
+    pub fn defragment(&mut self) -> ArrayVec<[U; N]> {
+        let mut remap = Self::new_idx_vec();
+        let mut next_idx = 0;
+
+        // Invariant: all slots in `next_idx..old_idx` are empty
+        for old_idx in 0..self.vec.len() {
+            remap.push(U::checked_from(next_idx));
+            if self.vec[old_idx].is_some() {
+                self.vec.swap(old_idx, next_idx);
+                next_idx += 1;
+            }
+        }
+
+        self.vec.truncate(next_idx);
+
+        #[cfg(not(feature = "low_mem_insert"))]
+        self.free_list.clear();
+
+        remap
+    }
+
     /// Returns the number of entries in the arena, some of which may be `None`.
     pub fn len(&self) -> usize {
         self.vec.len()
     }
 
+    /// Returns occupancy statistics for the arena's backing storage.
+    pub fn stats(&self) -> ArenaStats {
+        ArenaStats {
+            live: self.iter_occupied().count(),
+            holes: self.vec.iter().filter(|slot| slot.is_none()).count(),
+            backing_len: self.vec.len(),
+        }
+    }
+
     /// Returns true if the index is occupied, e.g. `Some(node)`.
     pub fn is_occupied(&self, idx: usize) -> bool {
         (idx < self.vec.len()) && (self.vec[idx].is_some())
@@ -200,6 +274,20 @@ impl<
     }
 }
 
+// Const construction requires a concrete index type, `U::default()` isn't `const`.
+impl<K: Default, V: Default, const N: usize> Arena<K, V, Idx, N> {
+    // Array repeat operands must be `Copy` or a constant, nodes aren't `Copy`.
+    const EMPTY_SLOT: Option<Node<K, V, Idx>> = None;
+
+    /// Const constructor, equivalent to [`Arena::new`].
+    pub const EMPTY: Self = Arena {
+        vec: ArrayVec::from_array_empty([Self::EMPTY_SLOT; N]),
+
+        #[cfg(not(feature = "low_mem_insert"))]
+        free_list: ArrayVec::from_array_empty([0; N]),
+    };
+}
+
 // Convenience Traits --------------------------------------------------------------------------------------------------
 
 /// Immutable indexing.
@@ -326,6 +414,54 @@ mod tests {
         assert_eq!(n_5_idx, 3);
     }
 
+    #[test]
+    fn test_iter_occupied() {
+        let mut arena: Arena<isize, &str, small_unsigned!(CAPACITY), CAPACITY> = Arena::new();
+
+        for key in 0..5 {
+            arena.add(key, "n/a");
+        }
+
+        // Create holes
+        arena.remove(1);
+        arena.remove(3);
+        assert_eq!(arena.len(), 5);
+
+        assert_eq!(arena.iter_occupied().count(), 3);
+        assert!(arena.iter_occupied().map(|n| *n.key()).eq([0, 2, 4]));
+
+        arena
+            .iter_occupied_mut()
+            .for_each(|n| *n.get_mut().1 = "mut");
+        assert_eq!(arena.iter_occupied_mut().count(), 3);
+        assert!(arena.iter_occupied().all(|n| *n.val() == "mut"));
+    }
+
+    #[test]
+    fn test_defragment() {
+        let mut arena: Arena<isize, &str, small_unsigned!(CAPACITY), CAPACITY> = Arena::new();
+
+        for key in 0..6 {
+            arena.add(key, "n/a");
+        }
+
+        // Fragment
+        arena.remove(0);
+        arena.remove(2);
+        arena.remove(3);
+        assert_eq!(arena.len(), 6);
+
+        let remap = arena.defragment();
+        assert_eq!(arena.len(), 3);
+        assert!(arena.iter_occupied().map(|n| *n.key()).eq([1, 4, 5]));
+        assert_eq!(remap[1], 0);
+        assert_eq!(remap[4], 1);
+        assert_eq!(remap[5], 2);
+
+        // Free list reset, next add appends
+        assert_eq!(arena.add(6, "n/a"), 3);
+    }
+
     #[test]
     fn test_index_mut() {
         let mut arena: Arena<isize, &str, small_unsigned!(CAPACITY), CAPACITY> = Arena::new();
@@ -12,17 +12,14 @@ pub enum SgError {
     /// Requested operation cannot complete, heap storage is full.
     HeapCapacityExceeded,
     */
-    /// Reserved for future use
-    #[doc(hidden)]
-    Reserved3,
+    /// Requested operation cannot complete, provided buffer is too small.
+    BufferTooSmall,
 
-    /// Reserved for future use
-    #[doc(hidden)]
-    Reserved4,
+    /// Requested operation cannot complete, tree would exceed its configured maximum height.
+    MaximumHeightExceeded,
 
-    /// Reserved for future use
-    #[doc(hidden)]
-    Reserved5,
+    /// Requested operation cannot complete, key is already present.
+    DuplicateKey,
 
     /// Reserved for future use
     #[doc(hidden)]
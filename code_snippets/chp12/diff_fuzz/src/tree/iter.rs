@@ -1,8 +1,12 @@
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::ops::RangeBounds;
+
 use tinyvec::ArrayVec;
 
-use super::node::Node;
+use super::arena::OccupiedIterMut;
 use super::node_dispatch::SmallNode;
-use super::tree::{Idx, SgTree};
+use super::tree::{cmp_to_range, Idx, SgTree};
 
 // Immutable Reference Iterator ----------------------------------------------------------------------------------------
 
@@ -76,6 +80,11 @@ impl<'a, K: Ord + Default, V: Default, const N: usize> Iterator for Iter<'a, K,
             None => None,
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
 }
 
 impl<'a, K: Ord + Default, V: Default, const N: usize> ExactSizeIterator for Iter<'a, K, V, N> {
@@ -85,19 +94,71 @@ impl<'a, K: Ord + Default, V: Default, const N: usize> ExactSizeIterator for Ite
     }
 }
 
+// Manual impl, traversal state is cloneable regardless of `K` and `V`
+impl<'a, K: Default, V: Default, const N: usize> Clone for Iter<'a, K, V, N> {
+    fn clone(&self) -> Self {
+        Iter {
+            bst: self.bst,
+            idx_stack: self.idx_stack,
+            total_cnt: self.total_cnt,
+            spent_cnt: self.spent_cnt,
+        }
+    }
+}
+
 // Mutable Reference Iterator ------------------------------------------------------------------------------------------
 
-pub struct IterMut<'a, K, V, const N: usize> {
-    arena_iter_mut: core::slice::IterMut<'a, Option<Node<K, V, Idx>>>,
+pub struct IterMut<'a, K: Ord + Default, V: Default, const N: usize> {
+    arena_iter_mut: OccupiedIterMut<'a, K, V, Idx>,
+    remaining_cnt: usize,
+
+    // Keys are shared refs, so interior mutability could change their order - checked against the next key
+    #[cfg(debug_assertions)]
+    opt_prev_key: Option<&'a K>,
 }
 
 impl<'a, K: Ord + Default, V: Default, const N: usize> IterMut<'a, K, V, N> {
     pub fn new(bst: &'a mut SgTree<K, V, N>) -> Self {
         bst.sort_arena();
         IterMut {
-            arena_iter_mut: bst.arena.iter_mut(),
+            remaining_cnt: bst.len(),
+            arena_iter_mut: bst.arena.iter_occupied_mut(),
+
+            #[cfg(debug_assertions)]
+            opt_prev_key: None,
         }
     }
+
+    /// Split into a pair of disjoint iterators, over keys less than `key` and keys greater than or equal to it.
+    /// Sorting packs nodes into the front of the arena in key order, so the halves are non-overlapping sub-slices.
+    pub fn new_split<Q>(bst: &'a mut SgTree<K, V, N>, key: &Q) -> (Self, Self)
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let lo_cnt = bst.iter().take_while(|(k, _)| (*k).borrow() < key).count();
+        let hi_cnt = bst.len() - lo_cnt;
+
+        bst.sort_arena();
+        let (lo_iter, hi_iter) = bst.arena.split_occupied_mut(lo_cnt);
+
+        (
+            IterMut {
+                arena_iter_mut: lo_iter,
+                remaining_cnt: lo_cnt,
+
+                #[cfg(debug_assertions)]
+                opt_prev_key: None,
+            },
+            IterMut {
+                arena_iter_mut: hi_iter,
+                remaining_cnt: hi_cnt,
+
+                #[cfg(debug_assertions)]
+                opt_prev_key: None,
+            },
+        )
+    }
 }
 
 impl<'a, K: Ord + Default, V: Default, const N: usize> Iterator for IterMut<'a, K, V, N> {
@@ -105,25 +166,109 @@ impl<'a, K: Ord + Default, V: Default, const N: usize> Iterator for IterMut<'a,
 
     fn next(&mut self) -> Option<Self::Item> {
         match self.arena_iter_mut.next() {
-            Some(Some(node)) => Some(node.get_mut()),
-            _ => None,
+            Some(node) => {
+                self.remaining_cnt -= 1;
+                let (key, val) = node.get_mut();
+
+                #[cfg(debug_assertions)]
+                {
+                    if let Some(prev_key) = self.opt_prev_key {
+                        debug_assert!(
+                            prev_key < key,
+                            "Key order changed during mutable iteration (interior mutability?)!"
+                        );
+                    }
+                    self.opt_prev_key = Some(key);
+                }
+
+                Some((key, val))
+            }
+            None => None,
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
 }
 
 impl<'a, K: Ord + Default, V: Default, const N: usize> ExactSizeIterator for IterMut<'a, K, V, N> {
     fn len(&self) -> usize {
-        self.arena_iter_mut.len()
+        self.remaining_cnt
+    }
+}
+
+// Mutable Reference Range Iterator ------------------------------------------------------------------------------------
+
+/// Reuses the sorted-arena traversal of `IterMut`, skipping keys below the range and stopping after the last key within it.
+pub struct RangeMut<'a, K: Ord + Default, V: Default, const N: usize> {
+    iter_mut: IterMut<'a, K, V, N>,
+    remaining_cnt: usize,
+}
+
+impl<'a, K: Ord + Default, V: Default, const N: usize> RangeMut<'a, K, V, N> {
+    pub fn new<Q, R>(bst: &'a mut SgTree<K, V, N>, range: R) -> Self
+    where
+        K: Borrow<Q>,
+        R: RangeBounds<Q>,
+        Q: Ord + ?Sized,
+    {
+        // Locate the window before `IterMut` takes the mutable borrow
+        let skip_cnt = bst
+            .iter()
+            .take_while(|(k, _)| cmp_to_range((*k).borrow(), &range) == Ordering::Less)
+            .count();
+        let remaining_cnt = bst
+            .iter()
+            .skip(skip_cnt)
+            .take_while(|(k, _)| cmp_to_range((*k).borrow(), &range) == Ordering::Equal)
+            .count();
+
+        let mut iter_mut = IterMut::new(bst);
+        if skip_cnt > 0 {
+            iter_mut.nth(skip_cnt - 1);
+        }
+
+        RangeMut {
+            iter_mut,
+            remaining_cnt,
+        }
+    }
+}
+
+impl<'a, K: Ord + Default, V: Default, const N: usize> Iterator for RangeMut<'a, K, V, N> {
+    type Item = (&'a K, &'a mut V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining_cnt == 0 {
+            return None;
+        }
+
+        self.remaining_cnt -= 1;
+        self.iter_mut.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, K: Ord + Default, V: Default, const N: usize> ExactSizeIterator for RangeMut<'a, K, V, N> {
+    fn len(&self) -> usize {
+        self.remaining_cnt
     }
 }
 
 // Consuming Iterator --------------------------------------------------------------------------------------------------
 
 /// Cheats a little by using internal flattening logic to sort, instead of re-implementing proper traversal.
-/// Maintains a shrinking list of arena indexes, initialized with all of them.
+/// Maintains a list of arena indexes, initialized with all of them, consumed from the front and/or back.
 pub struct IntoIter<K: Default, V: Default, const N: usize> {
     bst: SgTree<K, V, N>,
     sorted_idxs: ArrayVec<[usize; N]>,
+    front_pos: usize,
 }
 
 impl<K: Ord + Default, V: Default, const N: usize> IntoIter<K, V, N> {
@@ -131,36 +276,208 @@ impl<K: Ord + Default, V: Default, const N: usize> IntoIter<K, V, N> {
         let mut ordered_iter = IntoIter {
             bst,
             sorted_idxs: ArrayVec::<[usize; N]>::new(),
+            front_pos: 0,
         };
 
         if let Some(root_idx) = ordered_iter.bst.opt_root_idx {
             ordered_iter.sorted_idxs = ordered_iter.bst.flatten_subtree_to_sorted_idxs(root_idx);
-            ordered_iter.sorted_idxs.reverse();
         }
 
         ordered_iter
     }
+
+    // Remove and return the entry at a given arena index.
+    fn take(&mut self, idx: usize) -> Option<(K, V)> {
+        match self.bst.priv_remove_by_idx(idx) {
+            Some((key, val)) => Some((key, val)),
+            None => {
+                debug_assert!(false, "Use of invalid index in consuming iterator!");
+                None
+            }
+        }
+    }
 }
 
 impl<K: Ord + Default, V: Default, const N: usize> Iterator for IntoIter<K, V, N> {
     type Item = (K, V);
 
     fn next(&mut self) -> Option<Self::Item> {
-        match self.sorted_idxs.pop() {
-            Some(idx) => match self.bst.priv_remove_by_idx(idx) {
-                Some((key, val)) => Some((key, val)),
-                None => {
-                    debug_assert!(false, "Use of invalid index in consuming iterator!");
-                    None
-                }
-            },
+        match self.sorted_idxs.get(self.front_pos).copied() {
+            Some(idx) => {
+                self.front_pos += 1;
+                self.take(idx)
+            }
             None => None,
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<K: Ord + Default, V: Default, const N: usize> DoubleEndedIterator for IntoIter<K, V, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.len() {
+            0 => None,
+            _ => match self.sorted_idxs.pop() {
+                Some(idx) => self.take(idx),
+                None => None,
+            },
+        }
+    }
 }
 
 impl<K: Ord + Default, V: Default, const N: usize> ExactSizeIterator for IntoIter<K, V, N> {
     fn len(&self) -> usize {
-        self.sorted_idxs.len()
+        debug_assert!(self.front_pos <= self.sorted_idxs.len());
+        self.sorted_idxs.len() - self.front_pos
+    }
+}
+
+// Test ----------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::{IntoIter, Iter, IterMut, RangeMut};
+    use crate::tree::SgTree;
+    use core::cell::Cell;
+    use core::cmp::Ordering;
+    use core::ops::Bound;
+
+    const CAPACITY: usize = 16;
+
+    #[test]
+    fn test_range_mut_bounds() {
+        let mut bst: SgTree<usize, usize, CAPACITY> = (0..8).map(|i| (i, i)).collect();
+
+        let range = RangeMut::new(&mut bst, (Bound::Excluded(2), Bound::Included(5)));
+        assert_eq!(range.len(), 3);
+        for (_, v) in range {
+            *v += 10;
+        }
+
+        assert!(bst.iter().map(|(k, v)| (*k, *v)).eq([
+            (0, 0),
+            (1, 1),
+            (2, 2),
+            (3, 13),
+            (4, 14),
+            (5, 15),
+            (6, 6),
+            (7, 7)
+        ]));
+
+        assert_eq!(RangeMut::new(&mut bst, 6..).count(), 2);
+        assert_eq!(RangeMut::new(&mut bst, ..).count(), 8);
+        assert_eq!(RangeMut::new(&mut bst, 8..).count(), 0);
+        assert_eq!(RangeMut::new(&mut bst, 4..4).count(), 0);
+    }
+
+    fn assert_exact_size_hint<I: ExactSizeIterator>(mut iter: I, expected_len: usize) {
+        assert_eq!(iter.size_hint(), (expected_len, Some(expected_len)));
+        assert_eq!(iter.len(), expected_len);
+
+        iter.next();
+        assert_eq!(iter.size_hint(), (expected_len - 1, Some(expected_len - 1)));
+        assert_eq!(iter.len(), expected_len - 1);
+
+        iter.by_ref().for_each(drop);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    fn test_size_hint() {
+        let mut bst: SgTree<usize, usize, CAPACITY> = (0..8).map(|i| (i, i)).collect();
+
+        // Leave free slots in the arena
+        bst.remove(&1);
+        bst.remove(&6);
+
+        assert_exact_size_hint(Iter::new(&bst), 6);
+        assert_exact_size_hint(IterMut::new(&mut bst), 6);
+        assert_exact_size_hint(RangeMut::new(&mut bst, 2..), 5);
+        assert_exact_size_hint(IntoIter::new(bst), 6);
+    }
+
+    #[test]
+    fn test_into_iter_double_ended() {
+        let bst: SgTree<usize, usize, CAPACITY> = (0..8).map(|i| (i, i * 10)).collect();
+        let mut into_iter = IntoIter::new(bst);
+
+        assert_eq!(into_iter.next_back(), Some((7, 70)));
+        assert_eq!(into_iter.next(), Some((0, 0)));
+        assert_eq!(into_iter.next_back(), Some((6, 60)));
+        assert_eq!(into_iter.len(), 5);
+
+        // Front and back meet without overlap
+        assert!(into_iter.by_ref().rev().map(|(k, _)| k).eq([5, 4, 3, 2, 1]));
+        assert_eq!(into_iter.next(), None);
+        assert_eq!(into_iter.next_back(), None);
+    }
+
+    #[test]
+    fn test_iter_mut_value_mutation() {
+        let mut bst: SgTree<usize, usize, CAPACITY> = (0..8).map(|i| (i, i)).collect();
+
+        // Partial iteration
+        for (_, v) in IterMut::new(&mut bst).take(3) {
+            *v *= 10;
+        }
+
+        // Full iteration
+        IterMut::new(&mut bst).for_each(|(_, v)| *v += 1);
+
+        assert!(bst.iter().map(|(k, v)| (*k, *v)).eq([
+            (0, 1),
+            (1, 11),
+            (2, 21),
+            (3, 4),
+            (4, 5),
+            (5, 6),
+            (6, 7),
+            (7, 8)
+        ]));
+    }
+
+    // Key with interior mutability, can change order through a shared ref
+    #[derive(Default, PartialEq, Eq)]
+    struct CellKey(Cell<usize>);
+
+    impl PartialOrd for CellKey {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for CellKey {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.0.get().cmp(&other.0.get())
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Key order changed during mutable iteration")]
+    fn test_iter_mut_key_mutation() {
+        let mut bst: SgTree<CellKey, usize, CAPACITY> =
+            (0..8).map(|i| (CellKey(Cell::new(i)), i)).collect();
+
+        let mut iter_mut = IterMut::new(&mut bst);
+        if let Some((k, _)) = iter_mut.next() {
+            k.0.set(100);
+        }
+        iter_mut.next();
+    }
+
+    #[test]
+    fn test_iter_mut_borrow_ends_after_use() {
+        let mut bst: SgTree<usize, usize, CAPACITY> = (0..8).map(|i| (i, i)).collect();
+
+        // No drop glue, so the borrow ends at last use (in debug builds too)
+        let mut iter_mut = IterMut::new(&mut bst);
+        iter_mut.next();
+        assert_eq!(bst.len(), 8);
     }
 }
@@ -2,11 +2,12 @@ mod node_dispatch;
 pub use node_dispatch::SmallNode;
 
 mod arena;
+pub use arena::ArenaStats;
 
 pub(super) mod node;
 
 mod iter;
-pub use iter::{IntoIter, Iter, IterMut};
+pub use iter::{IntoIter, Iter, IterMut, RangeMut};
 
 mod error;
 pub use error::SgError;
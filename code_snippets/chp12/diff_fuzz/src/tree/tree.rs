@@ -2,13 +2,13 @@ use core::borrow::Borrow;
 use core::cmp::Ordering;
 use core::fmt::{self, Debug};
 use core::hash::{Hash, Hasher};
-use core::iter::FromIterator;
+use core::iter::{FromIterator, Skip, Take};
 use core::mem;
-use core::ops::{Index, Sub};
+use core::ops::{Bound, Index, RangeBounds, Sub};
 
-use super::arena::Arena;
+use super::arena::{Arena, ArenaStats};
 use super::error::SgError;
-use super::iter::{IntoIter, Iter, IterMut};
+use super::iter::{IntoIter, Iter, IterMut, RangeMut};
 use super::node::{NodeGetHelper, NodeRebuildHelper};
 use super::node_dispatch::SmallNode;
 
@@ -17,13 +17,27 @@ use micromath::F32Ext;
 use smallnum::SmallUnsigned;
 use tinyvec::{array_vec, ArrayVec};
 
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String};
+
 // The `u16::MAX` limit is documented in our main `README.md`.
+// `new` rejects any `N` over this limit, and all arena indexes, path lengths, and subtree sizes are bounded by `N`.
+// So internal `Idx::checked_from` conversions cannot overflow, no matter the sequence of public API calls.
 pub type Idx = u16;
 
 // See: https://github.com/tnballo/scapegoat/blob/master/CONFIG.md
 const DEFAULT_ALPHA_NUM: f32 = 2.0;
 const DEFAULT_ALPHA_DENOM: f32 = 3.0;
 
+// Bounds for `suggest_alpha`: rebuilds per element considered insert-neutral, and the highest alpha suggested.
+const SUGGEST_REBAL_RATE_PIVOT: f32 = 0.1;
+const SUGGEST_ALPHA_MAX: f32 = 0.95;
+
+// Minimum height of a tree with `len` nodes, e.g. ceil(log2(len + 1)).
+fn optimal_height(len: usize) -> usize {
+    (usize::BITS - len.leading_zeros()) as usize
+}
+
 /// A memory-efficient, self-balancing binary search tree.
 #[derive(Clone)]
 pub struct SgTree<K: Default, V: Default, const N: usize> {
@@ -41,11 +55,33 @@ pub struct SgTree<K: Default, V: Default, const N: usize> {
     alpha_denom: f32,
     max_size: usize,
     rebal_cnt: usize,
+    opt_rebal_callback: Option<fn(usize, usize)>,
+    opt_max_height: Option<usize>,
 }
 
 impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
     // Public API ------------------------------------------------------------------------------------------------------
 
+    /// An empty `SgTree`, for `const`/`static` initialization.
+    /// Equivalent to [`SgTree::new`], except exceeding the maximum capacity is a compile-time error.
+    pub const EMPTY: Self = {
+        assert!(N <= Idx::MAX as usize, "Max stack item capacity exceeded!");
+
+        SgTree {
+            arena: Arena::EMPTY,
+            opt_root_idx: None,
+            max_idx: 0,
+            min_idx: 0,
+            curr_size: 0,
+            alpha_num: DEFAULT_ALPHA_NUM,
+            alpha_denom: DEFAULT_ALPHA_DENOM,
+            max_size: 0,
+            rebal_cnt: 0,
+            opt_rebal_callback: None,
+            opt_max_height: None,
+        }
+    };
+
     /// Makes a new, empty `SgTree`.
     pub fn new() -> Self {
         if N > SgTree::<K, V, N>::max_capacity() {
@@ -62,6 +98,8 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
             alpha_denom: DEFAULT_ALPHA_DENOM,
             max_size: 0,
             rebal_cnt: 0,
+            opt_rebal_callback: None,
+            opt_max_height: None,
         }
     }
 
@@ -94,6 +132,70 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
         (self.alpha_num, self.alpha_denom)
     }
 
+    /// Suggest a rebalance parameter, alpha, for the workload observed so far, as `(alpha_numerator, alpha_denominator)`.
+    ///
+    /// This is a heuristic, not a guarantee.
+    /// The tree doesn't count lookups, so workload is inferred from rebuilds per element ([`rebal_cnt`][SgTree::rebal_cnt]
+    /// relative to `len`) and from how far the tree's height exceeds the optimal height:
+    ///
+    /// * Frequent rebuilds (e.g. sorted inserts) suggest an insert-heavy workload, trending toward `1.0`.
+    /// * Rare rebuilds suggest a read-heavy workload, trending toward `0.5`.
+    /// * A tall tree, relative to optimal, tempers the trend toward `1.0`, since lookups already pay for that height.
+    ///
+    /// Statistics accumulate until [`clear_stats`][SgTree::clear_stats] or [`reset_rebal_cnt`][SgTree::reset_rebal_cnt],
+    /// so suggestions reflect all prior operations.
+    /// An empty tree has no observed workload, and gets back its current alpha.
+    pub fn suggest_alpha(&self) -> (f32, f32) {
+        const ALPHA_DENOM: f32 = 100.0;
+
+        if self.is_empty() {
+            return self.rebal_param();
+        }
+
+        // Insert pressure in [0.0, 1.0), 0.5 at 1 rebuild per `SUGGEST_REBAL_RATE_PIVOT` elements
+        let rebal_rate = self.rebal_cnt as f32 / self.len() as f32;
+        let insert_pressure = rebal_rate / (rebal_rate + SUGGEST_REBAL_RATE_PIVOT);
+
+        // Height slack in (0.0, 1.0], 1.0 for optimal balance
+        let height_slack = optimal_height(self.len()) as f32 / self.height() as f32;
+
+        let a = 0.5 + (insert_pressure * height_slack * (SUGGEST_ALPHA_MAX - 0.5));
+        ((a * ALPHA_DENOM).round(), ALPHA_DENOM)
+    }
+
+    /// Apply the rebalance parameter from [`suggest_alpha`][SgTree::suggest_alpha].
+    /// Takes effect for subsequent operations, existing structure isn't rebuilt.
+    pub fn auto_tune_alpha(&mut self) {
+        let (alpha_num, alpha_denom) = self.suggest_alpha();
+        let result = self.set_rebal_param(alpha_num, alpha_denom);
+        debug_assert!(result.is_ok(), "Suggested alpha out of range!");
+    }
+
+    /// Set a strict maximum height (number of nodes on the longest root-to-leaf path), or `None` for no limit (default).
+    /// Bounds worst-case lookup cost, e.g. for hard real-time use, at the expense of insert cost:
+    /// any insert that would exceed the limit triggers an immediate full rebuild, instead of amortized rebalancing.
+    ///
+    /// Even an optimally balanced tree of height `h` holds at most `2^h - 1` elements.
+    /// Fallible inserts return `Err` if a new element wouldn't fit within the limit, infallible inserts panic.
+    /// If the tree already exceeds the new limit, it's rebuilt immediately (best effort, if too full to fit).
+    pub fn set_max_height(&mut self, max: Option<usize>) {
+        self.opt_max_height = max;
+        self.enforce_max_height();
+    }
+
+    /// Get the maximum height, if any.
+    /// See [the corresponding setter method][SgTree::set_max_height] for more details.
+    pub fn max_height(&self) -> Option<usize> {
+        self.opt_max_height
+    }
+
+    /// Set a callback invoked on every subtree rebuild (for observing rebalancing at runtime, e.g. on-device logging).
+    /// Arguments are the arena index of the rebuilt subtree's root (the scapegoat) and the size of that subtree.
+    /// Unset by default, in which case rebuilds only pay for a single `None` check.
+    pub fn set_rebalance_callback(&mut self, cb: fn(scapegoat_idx: usize, subtree_size: usize)) {
+        self.opt_rebal_callback = Some(cb);
+    }
+
     /// Total capacity, e.g. maximum number of tree pairs.
     pub fn capacity(&self) -> usize {
         self.arena.capacity()
@@ -104,7 +206,17 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
         self.arena.node_size()
     }
 
+    /// Get occupancy statistics for the tree's internal arena, see [`ArenaStats`].
+    pub fn arena_stats(&self) -> ArenaStats {
+        self.arena.stats()
+    }
+
     /// Moves all elements from `other` into `self`, leaving `other` empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the union of `self` and `other` exceeds the tree's capacity, see [`try_append`][SgTree::try_append].
+    /// Neither tree is modified before the panic.
     pub fn append(&mut self, other: &mut SgTree<K, V, N>)
     where
         K: Ord,
@@ -117,9 +229,21 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
         // Nothing to append to!
         if self.is_empty() {
             mem::swap(self, other);
+            mem::swap(&mut self.opt_max_height, &mut other.opt_max_height);
+            mem::swap(&mut self.opt_rebal_callback, &mut other.opt_rebal_callback);
+            self.enforce_max_height();
             return;
         }
 
+        // Preemptive - fail before `other`'s arena is partially drained
+        let union_cnt = self.len() + other.len() - self.intersect_cnt(other);
+        assert!(
+            union_cnt <= self.capacity(),
+            "Append exceeds stack capacity ({} > {}), use `try_append` to handle this case",
+            union_cnt,
+            self.capacity()
+        );
+
         // Rip elements directly out of other's arena and clear it
         for arena_idx in 0..other.arena.len() {
             if let Some(mut node) = other.arena.remove(arena_idx) {
@@ -136,25 +260,26 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
             return Ok(());
         }
 
+        // Preemptive - we haven't mutated `self` or `other`!
+        // Caller can assume unchanged state.
+        self.check_growth(other.len() - self.intersect_cnt(other))?;
+
         // Nothing to append to!
         if self.is_empty() {
             mem::swap(self, other);
+            mem::swap(&mut self.opt_max_height, &mut other.opt_max_height);
+            mem::swap(&mut self.opt_rebal_callback, &mut other.opt_rebal_callback);
+            self.enforce_max_height();
             return Ok(());
         }
 
         // Rip elements directly out of other's arena and clear it
-        if (self.len() + other.len() - self.intersect_cnt(other)) <= self.capacity() {
-            for arena_idx in 0..other.arena.len() {
-                if let Some(mut node) = other.arena.remove(arena_idx) {
-                    self.try_insert(node.take_key(), node.take_val())?;
-                }
+        for arena_idx in 0..other.arena.len() {
+            if let Some(mut node) = other.arena.remove(arena_idx) {
+                self.try_insert(node.take_key(), node.take_val())?;
             }
-            other.clear();
-        } else {
-            // Preemptive - we haven't mutated `self` or `other`!
-            // Caller can assume unchanged state.
-            return Err(SgError::StackCapacityExceeded);
         }
+        other.clear();
 
         Ok(())
     }
@@ -180,10 +305,24 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
         K: Ord,
     {
         // Replace current slot or safely fill a new one
-        match self.contains_key(&key) || (self.capacity() > self.len()) {
-            true => Ok(self.priv_balancing_insert::<Idx>(key, val).0),
-            false => Err(SgError::StackCapacityExceeded),
+        if !self.contains_key(&key) {
+            self.check_growth(1)?;
         }
+
+        Ok(self.priv_balancing_insert::<Idx>(key, val).0)
+    }
+
+    /// Attempt to extend the tree with the contents of an iterator, optimized for ascending keys.
+    /// In-order keys are appended to the right spine, deferring rebalancing to a single rebuild.
+    /// Out-of-order keys fall back to a regular insert, after restoring balance.
+    /// Returns `Err` without modifying the tree if the iterator's length exceeds remaining capacity.
+    pub fn try_insert_sorted<I: ExactSizeIterator<Item = (K, V)>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), SgError> {
+        self.check_growth(iter.len())?;
+        self.priv_insert_sorted(iter);
+        Ok(())
     }
 
     // Attempt to extend a collection with the contents of an iterator.
@@ -191,14 +330,93 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
         &mut self,
         iter: I,
     ) -> Result<(), SgError> {
-        if iter.len() <= (self.capacity() - self.len()) {
-            iter.into_iter().for_each(move |(k, v)| {
-                assert!(self.try_insert(k, v).is_ok());
-            });
-            Ok(())
-        } else {
-            Err(SgError::StackCapacityExceeded)
+        self.check_growth(iter.len())?;
+        iter.into_iter().for_each(move |(k, v)| {
+            assert!(self.try_insert(k, v).is_ok());
+        });
+        Ok(())
+    }
+
+    /// Attempt to extend a tree with the contents of an iterator whose items may overwrite existing keys.
+    /// Only keys not already present count against remaining capacity, so a full tree can still accept updates.
+    ///
+    /// Makes two passes over the iterator: the first buffers distinct new keys (at most the remaining capacity)
+    /// to decide if all items fit, the second inserts. The tree is left unmodified on error.
+    pub fn try_extend_updates<I>(&mut self, iter: I) -> Result<(), SgError>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        I::IntoIter: Clone,
+    {
+        let iter = iter.into_iter();
+        let remaining = self.capacity() - self.len();
+        let mut new_keys: ArrayVec<[K; N]> = ArrayVec::new();
+
+        for (k, _) in iter.clone() {
+            if self.contains_key(&k) {
+                continue;
+            }
+
+            if let Err(pos) = new_keys.binary_search(&k) {
+                if new_keys.len() >= remaining {
+                    return Err(SgError::StackCapacityExceeded);
+                }
+                new_keys.insert(pos, k);
+            }
+        }
+        self.check_growth(new_keys.len())?;
+
+        iter.for_each(move |(k, v)| {
+            assert!(self.try_insert(k, v).is_ok());
+        });
+        Ok(())
+    }
+
+    /// Insert a key-value pair into the tree, only if the key isn't already present.
+    /// Returns `Err` if the key is present (the existing pair is left unchanged) or if the tree has no room for it.
+    pub fn try_insert_unique(&mut self, key: K, val: V) -> Result<(), SgError> {
+        if self.contains_key(&key) {
+            return Err(SgError::DuplicateKey);
+        }
+
+        self.check_growth(1)?;
+        self.priv_balancing_insert::<Idx>(key, val);
+        Ok(())
+    }
+
+    /// Attempt to extend a tree with the contents of an iterator, none of whose keys may already be present or repeat.
+    ///
+    /// Makes two passes over the iterator: the first buffers new keys (at most the remaining capacity)
+    /// to check for duplicates and fit, the second inserts. The tree is left unmodified on error.
+    pub fn try_extend_unique<I>(&mut self, iter: I) -> Result<(), SgError>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        I::IntoIter: Clone,
+    {
+        let iter = iter.into_iter();
+        let remaining = self.capacity() - self.len();
+        let mut new_keys: ArrayVec<[K; N]> = ArrayVec::new();
+
+        for (k, _) in iter.clone() {
+            if self.contains_key(&k) {
+                return Err(SgError::DuplicateKey);
+            }
+
+            match new_keys.binary_search(&k) {
+                Ok(_) => return Err(SgError::DuplicateKey),
+                Err(pos) => {
+                    if new_keys.len() >= remaining {
+                        return Err(SgError::StackCapacityExceeded);
+                    }
+                    new_keys.insert(pos, k);
+                }
+            }
         }
+        self.check_growth(new_keys.len())?;
+
+        iter.for_each(move |(k, v)| {
+            self.priv_balancing_insert::<Idx>(k, v);
+        });
+        Ok(())
     }
 
     // Attempt conversion from an iterator.
@@ -212,6 +430,17 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
         }
     }
 
+    /// Conversion from an iterator, returning `Err` instead of panicking if capacity is exceeded.
+    pub fn collect_checked<I: IntoIterator<Item = (K, V)>>(iter: I) -> Result<Self, SgError> {
+        let mut sgt = SgTree::new();
+
+        for (k, v) in iter {
+            sgt.try_insert(k, v)?;
+        }
+
+        Ok(sgt)
+    }
+
     /// Gets an iterator over the entries of the tree, sorted by key.
     pub fn iter(&self) -> Iter<'_, K, V, N> {
         Iter::new(self)
@@ -222,6 +451,44 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
         IterMut::new(self)
     }
 
+    /// Gets a mutable iterator over a sub-range of entries in the tree, sorted by key.
+    ///
+    /// The key may be any borrowed form of the map’s key type, but the ordering
+    /// on the borrowed form must match the ordering on the key type.
+    pub fn range_mut<Q, R>(&mut self, range: R) -> RangeMut<'_, K, V, N>
+    where
+        K: Borrow<Q> + Ord,
+        R: RangeBounds<Q>,
+        Q: Ord + ?Sized,
+    {
+        RangeMut::new(self, range)
+    }
+
+    /// Gets an iterator over a sub-range of entries in the tree, sorted by key.
+    /// Empty and inverted ranges yield nothing.
+    ///
+    /// The key may be any borrowed form of the map’s key type, but the ordering
+    /// on the borrowed form must match the ordering on the key type.
+    pub fn get_range<Q, R>(&self, range: R) -> Take<Skip<Iter<'_, K, V, N>>>
+    where
+        K: Borrow<Q> + Ord,
+        R: RangeBounds<Q>,
+        Q: Ord + ?Sized,
+    {
+        // Locate the window eagerly, so the returned iterator doesn't borrow `range`
+        let skip_cnt = self
+            .iter()
+            .take_while(|(k, _)| cmp_to_range((*k).borrow(), &range) == Ordering::Less)
+            .count();
+        let window_cnt = self
+            .iter()
+            .skip(skip_cnt)
+            .take_while(|(k, _)| cmp_to_range((*k).borrow(), &range) == Ordering::Equal)
+            .count();
+
+        self.iter().skip(skip_cnt).take(window_cnt)
+    }
+
     /// Removes a key from the tree, returning the stored key and value if the key was previously in the tree.
     ///
     /// The key may be any borrowed form of the map’s key type, but the ordering
@@ -257,6 +524,24 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
         self.remove_entry(key).map(|(_, v)| v)
     }
 
+    /// Removes a key from the tree, returning the value at the key if the key was previously in the tree,
+    /// and the next-larger key remaining in the tree (the in-order successor), if any.
+    ///
+    /// The key may be any borrowed form of the map’s key type, but the ordering
+    /// on the borrowed form must match the ordering on the key type.
+    pub fn remove_and_next<Q>(&mut self, key: &Q) -> (Option<V>, Option<K>)
+    where
+        K: Borrow<Q> + Ord + Clone,
+        Q: Ord + ?Sized,
+    {
+        // Lookup prior to removal, doesn't rely on post-removal structure
+        let opt_next_key = self
+            .priv_get_successor_idx(key)
+            .map(|idx| self.arena[idx].key().clone());
+
+        (self.remove(key), opt_next_key)
+    }
+
     /// Retains only the elements specified by the predicate.
     pub fn retain<F>(&mut self, mut f: F)
     where
@@ -266,6 +551,45 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
         self.priv_drain_filter(|k, v| !f(k, v));
     }
 
+    /// Retains only the elements specified by the predicate, returning the removed elements as a new tree.
+    pub fn retain_collect<F>(&mut self, mut f: F) -> Self
+    where
+        F: FnMut(&K, &mut V) -> bool,
+        K: Ord,
+    {
+        self.priv_drain_filter(|k, v| !f(k, v))
+    }
+
+    /// Retains only the elements within the range, removing all others.
+    ///
+    /// The key may be any borrowed form of the map’s key type, but the ordering
+    /// on the borrowed form must match the ordering on the key type.
+    pub fn retain_range<Q, R>(&mut self, range: R)
+    where
+        K: Borrow<Q> + Ord,
+        R: RangeBounds<Q>,
+        Q: Ord + ?Sized,
+    {
+        let root_idx = match self.opt_root_idx {
+            Some(root_idx) => root_idx,
+            None => return,
+        };
+
+        // Out-of-range keys are a prefix and/or suffix of the ordered traversal
+        let sorted_idxs = self.flatten_subtree_to_sorted_idxs::<Idx>(root_idx);
+        let range_cmp = |idx: &Idx| cmp_to_range(self.arena[idx.usize()].key().borrow(), &range);
+        let start = sorted_idxs.partition_point(|idx| range_cmp(idx) == Ordering::Less);
+        let end = sorted_idxs.partition_point(|idx| range_cmp(idx) != Ordering::Greater);
+
+        // Arena indexes are stable across removals
+        for idx in sorted_idxs[..start]
+            .iter()
+            .chain(&sorted_idxs[end.max(start)..])
+        {
+            self.priv_remove_by_idx(idx.usize());
+        }
+    }
+
     /// Splits the collection into two at the given key. Returns everything after the given key, including the key.
     pub fn split_off<Q>(&mut self, key: &Q) -> Self
     where
@@ -284,8 +608,7 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
         K: Borrow<Q> + Ord,
         Q: Ord + ?Sized,
     {
-        let ngh: NodeGetHelper<Idx> = self.priv_get(None, key);
-        match ngh.node_idx() {
+        match self.priv_get_bounded(key) {
             Some(idx) => {
                 let node = &self.arena[idx];
                 Some((node.key(), node.val()))
@@ -315,8 +638,7 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
         K: Borrow<Q> + Ord,
         Q: Ord + ?Sized,
     {
-        let ngh: NodeGetHelper<Idx> = self.priv_get(None, key);
-        match ngh.node_idx() {
+        match self.priv_get_bounded(key) {
             Some(idx) => {
                 let (_, val) = self.arena[idx].get_mut();
                 Some(val)
@@ -326,14 +648,61 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
     }
 
     /// Clears the tree, removing all elements.
+    /// The rebalance count is preserved, for tracking performance over the tree's lifetime.
+    /// To reset it as well, use [`clear_stats`][SgTree::clear_stats].
+    /// The rebalance callback and maximum height, if any, are kept.
     pub fn clear(&mut self) {
         if !self.is_empty() {
-            let rebal_cnt = self.rebal_cnt;
-            *self = SgTree::new();
-            self.rebal_cnt = rebal_cnt;
+            *self = SgTree {
+                rebal_cnt: self.rebal_cnt,
+                opt_rebal_callback: self.opt_rebal_callback,
+                opt_max_height: self.opt_max_height,
+                ..SgTree::new()
+            };
         }
     }
 
+    /// Clears the tree, removing all elements, and resets rebalance statistics (count and max size) for a clean slate.
+    /// Configuration is handled as in [`clear`][SgTree::clear].
+    pub fn clear_stats(&mut self) {
+        self.clear();
+        self.rebal_cnt = 0;
+        self.max_size = 0;
+    }
+
+    /// Compacts the internal arena, removing empty slots left behind by removals.
+    /// Node indexes change, but the tree's logical contents and shape do not.
+    pub fn defragment(&mut self) {
+        let remap = self.arena.defragment();
+        let new_idx = |opt_idx: Option<usize>| opt_idx.map(|idx| remap[idx].usize());
+
+        for node in self.arena.iter_occupied_mut() {
+            node.set_left_idx(new_idx(node.left_idx()));
+            node.set_right_idx(new_idx(node.right_idx()));
+        }
+
+        self.opt_root_idx = new_idx(self.opt_root_idx);
+        self.update_min_idx();
+        self.update_max_idx();
+    }
+
+    /// Rebuilds the entire tree into optimal (height) balance, regardless of the current alpha.
+    /// Counts as a rebalance, see [`rebal_cnt`][SgTree::rebal_cnt].
+    pub fn rebalance(&mut self) {
+        if let Some(root_idx) = self.opt_root_idx {
+            self.rebuild::<Idx>(root_idx);
+        }
+    }
+
+    /// Compacts the internal arena and rebuilds the entire tree into optimal (height) balance, e.g. after heavy churn.
+    /// The rebalance parameter, alpha, is preserved.
+    /// The max size tracked since the last full rebuild resets to the current size, as if freshly built.
+    pub fn rebuild_balanced(&mut self) {
+        self.defragment();
+        self.rebalance();
+        self.max_size = self.curr_size;
+    }
+
     /// Returns `true` if the tree contains a value for the given key.
     ///
     /// The key may be any borrowed form of the map’s key type, but the
@@ -346,6 +715,24 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
         self.get(key).is_some()
     }
 
+    /// Returns the 0-based position of a key in sorted order, if present.
+    /// In-order traversal, `O(n)`, stopping early once past the key's position.
+    pub fn rank<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        for (i, (k, _)) in self.iter().enumerate() {
+            match k.borrow().cmp(key) {
+                Ordering::Less => continue,
+                Ordering::Equal => return Some(i),
+                Ordering::Greater => return None,
+            }
+        }
+
+        None
+    }
+
     /// Returns `true` if the tree contains no elements.
     pub fn is_empty(&self) -> bool {
         self.opt_root_idx.is_none()
@@ -371,6 +758,19 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
         }
     }
 
+    /// Returns the first key-value pair in the tree, with a mutable reference to the value.
+    /// The key in this pair is the minimum key in the tree.
+    pub fn first_key_value_mut(&mut self) -> Option<(&K, &mut V)>
+    where
+        K: Ord,
+    {
+        if !self.is_empty() {
+            Some(self.arena[self.min_idx].get_mut())
+        } else {
+            None
+        }
+    }
+
     /// Returns a reference to the first/minium key in the tree, if any.
     pub fn first_key(&self) -> Option<&K>
     where
@@ -402,6 +802,19 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
         }
     }
 
+    /// Returns the last key-value pair in the tree, with a mutable reference to the value.
+    /// The key in this pair is the maximum key in the tree.
+    pub fn last_key_value_mut(&mut self) -> Option<(&K, &mut V)>
+    where
+        K: Ord,
+    {
+        if !self.is_empty() {
+            Some(self.arena[self.max_idx].get_mut())
+        } else {
+            None
+        }
+    }
+
     /// Returns a reference to the last/maximum key in the tree, if any.
     pub fn last_key(&self) -> Option<&K>
     where
@@ -425,13 +838,150 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
     }
 
     /// Get the number of times this tree rebalanced itself (for testing and/or performance engineering).
-    /// This count will wrap if `usize::MAX` is exceeded.
+    /// This count will wrap, not panic, if `usize::MAX` is exceeded. See [`reset_rebal_cnt`][SgTree::reset_rebal_cnt].
     pub fn rebal_cnt(&self) -> usize {
         self.rebal_cnt
     }
 
+    /// Reset the rebalance count to zero, without modifying the tree's contents.
+    /// Unlike [`clear_stats`][SgTree::clear_stats], elements and max size are kept.
+    pub fn reset_rebal_cnt(&mut self) {
+        self.rebal_cnt = 0;
+    }
+
+    /// Panic if a structural invariant doesn't hold (oracle for testing and/or fuzzing).
+    /// Checks that every occupied arena node is reachable from the root, that an in-order traversal yields strictly
+    /// ascending keys, and that cached min/max indexes point at the first/last keys.
+    #[cfg(any(test, fuzzing))]
+    pub fn assert_invariants(&self) {
+        let occupied_cnt = self.arena.iter_occupied().count();
+        assert!(
+            occupied_cnt == self.len(),
+            "Arena occupancy ({}) doesn't match length ({})!",
+            occupied_cnt,
+            self.len()
+        );
+
+        let mut visited = [false; N];
+        let mut worklist = Arena::<K, V, Idx, N>::new_idx_vec();
+        let mut reachable_cnt = 0;
+
+        if let Some(root_idx) = self.opt_root_idx {
+            visited[root_idx] = true;
+            worklist.push(Idx::checked_from(root_idx));
+        }
+
+        while let Some(idx) = worklist.pop() {
+            let node = &self.arena[idx.usize()];
+            reachable_cnt += 1;
+
+            for child_idx in [node.left_idx(), node.right_idx()].into_iter().flatten() {
+                assert!(
+                    !visited[child_idx],
+                    "Node at arena index {} reachable more than once!",
+                    child_idx
+                );
+                visited[child_idx] = true;
+                worklist.push(Idx::checked_from(child_idx));
+            }
+        }
+
+        assert!(
+            reachable_cnt == self.len(),
+            "Only {} of {} nodes reachable from root!",
+            reachable_cnt,
+            self.len()
+        );
+
+        assert!(
+            self.iter().count() == self.len(),
+            "In-order traversal length doesn't match length!"
+        );
+        assert!(
+            self.iter()
+                .zip(self.iter().skip(1))
+                .all(|((k1, _), (k2, _))| k1 < k2),
+            "In-order traversal keys not strictly ascending!"
+        );
+
+        assert!(
+            self.first_key() == self.iter().next().map(|(k, _)| k),
+            "Cached min index doesn't point at first key!"
+        );
+        assert!(
+            self.last_key() == self.iter().last().map(|(k, _)| k),
+            "Cached max index doesn't point at last key!"
+        );
+    }
+
+    /// Render the tree's structure in [Graphviz DOT](https://graphviz.org/doc/info/lang.html) format, for visualizing
+    /// rebalancing. Nodes are named by arena index and labeled by key, edges are labeled left (`L`) or right (`R`).
+    #[cfg(feature = "alloc")]
+    pub fn to_dot(&self) -> String
+    where
+        K: Debug,
+    {
+        // Escape for a DOT quoted string
+        fn dot_label<K: Debug>(key: &K) -> String {
+            format!("{:?}", key)
+                .replace('\\', "\\\\")
+                .replace('"', "\\\"")
+        }
+
+        let mut dot = String::from("digraph SgTree {\n");
+        let mut worklist = Arena::<K, V, Idx, N>::new_idx_vec();
+
+        if let Some(root_idx) = self.opt_root_idx {
+            worklist.push(Idx::checked_from(root_idx));
+        }
+
+        while let Some(idx) = worklist.pop() {
+            let idx = idx.usize();
+            let node = &self.arena[idx];
+            dot.push_str(&format!(
+                "    n{} [label=\"{}\"];\n",
+                idx,
+                dot_label(node.key())
+            ));
+
+            for (label, opt_child_idx) in [("L", node.left_idx()), ("R", node.right_idx())] {
+                if let Some(child_idx) = opt_child_idx {
+                    dot.push_str(&format!(
+                        "    n{} -> n{} [label=\"{}\"];\n",
+                        idx, child_idx, label
+                    ));
+                    worklist.push(Idx::checked_from(child_idx));
+                }
+            }
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
     // Crate-internal API ----------------------------------------------------------------------------------------------
 
+    // Number of nodes on the longest root-to-leaf path.
+    pub(crate) fn height(&self) -> usize {
+        let mut max_height = 0;
+        let mut worklist = ArrayVec::<[(Idx, usize); N]>::default();
+
+        if let Some(root_idx) = self.opt_root_idx {
+            worklist.push((Idx::checked_from(root_idx), 1));
+        }
+
+        while let Some((idx, height)) = worklist.pop() {
+            let node = &self.arena[idx.usize()];
+            max_height = max_height.max(height);
+
+            for child_idx in [node.left_idx(), node.right_idx()].into_iter().flatten() {
+                worklist.push((Idx::checked_from(child_idx), height + 1));
+            }
+        }
+
+        max_height
+    }
+
     // Remove a node by index.
     // A wrapper for by-key removal, traversal is still required to determine node parent.
     #[cfg(not(feature = "fast_rebalance"))]
@@ -504,9 +1054,7 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
         if let Some(root_idx) = self.opt_root_idx {
             let mut sort_metadata = self
                 .arena
-                .iter()
-                .filter(|n| n.is_some())
-                .map(|n| n.as_ref().unwrap())
+                .iter_occupied()
                 .map(|n| self.priv_get(None, n.key()))
                 .collect::<ArrayVec<[NodeGetHelper<usize>; N]>>();
 
@@ -531,6 +1079,102 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
 
     // Private API -----------------------------------------------------------------------------------------------------
 
+    // Insert from an iterator, appending in-order keys to the right spine and deferring rebalancing (see `try_insert_sorted`).
+    // Caller must check that all items fit.
+    pub(crate) fn priv_insert_sorted<I: Iterator<Item = (K, V)>>(&mut self, iter: I) {
+        let mut deferred_cnt = 0;
+
+        for (k, v) in iter {
+            match self.opt_root_idx {
+                // New max, append as right child of previous max
+                Some(_) if &k > self.arena[self.max_idx].key() => {
+                    let new_node_idx = self.arena.add(k, v);
+                    self.arena[self.max_idx].set_right_idx(Some(new_node_idx));
+                    self.max_idx = new_node_idx;
+                    self.curr_size += 1;
+                    self.max_size += 1;
+                    deferred_cnt += 1;
+                }
+                // Empty tree or out-of-order key
+                _ => {
+                    if deferred_cnt > 0 {
+                        self.rebalance();
+                        deferred_cnt = 0;
+                    }
+                    self.priv_balancing_insert::<Idx>(k, v);
+                }
+            }
+        }
+
+        if deferred_cnt > 0 {
+            self.rebalance();
+        }
+    }
+
+    // Check that `cnt` new elements would fit, within both capacity and the maximum height (if any).
+    pub(crate) fn check_growth(&self, cnt: usize) -> Result<(), SgError> {
+        if cnt > (self.capacity() - self.len()) {
+            return Err(SgError::StackCapacityExceeded);
+        }
+
+        match self.opt_max_height {
+            Some(max) if optimal_height(self.len() + cnt) > max => {
+                Err(SgError::MaximumHeightExceeded)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    // Rebuild if the tree exceeds its maximum height (if any).
+    fn enforce_max_height(&mut self) {
+        if let Some(max) = self.opt_max_height {
+            if self.height() > max {
+                self.rebalance();
+            }
+        }
+    }
+
+    // Iterative search for the node with the smallest key strictly greater than the given key, which needn't be present.
+    fn priv_get_successor_idx<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        let mut opt_successor_idx = None;
+        let mut opt_curr_idx = self.opt_root_idx;
+
+        while let Some(curr_idx) = opt_curr_idx {
+            let node = &self.arena[curr_idx];
+            if node.key().borrow() > key {
+                opt_successor_idx = Some(curr_idx);
+                opt_curr_idx = node.left_idx();
+            } else {
+                opt_curr_idx = node.right_idx();
+            }
+        }
+
+        opt_successor_idx
+    }
+
+    // Lookup for public queries, returns node idx if key found.
+    // Keys outside the cached `[min, max]` bounds return early, skipping descent.
+    // Internal callers use `priv_get` directly, since the cache may be stale mid-operation (e.g. during a removal).
+    fn priv_get_bounded<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q> + Ord,
+        Q: Ord + ?Sized,
+    {
+        if self.is_empty()
+            || (key < self.arena[self.min_idx].key().borrow())
+            || (key > self.arena[self.max_idx].key().borrow())
+        {
+            return None;
+        }
+
+        let ngh: NodeGetHelper<Idx> = self.priv_get(None, key);
+        ngh.node_idx()
+    }
+
     // Iterative search. If key found, returns node idx, parent idx, and a bool indicating if node is right child
     // `opt_path` is only populated if `Some` and key is found.
     pub(crate) fn priv_get<Q, U: SmallUnsigned + Default + Copy>(
@@ -622,10 +1266,23 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
             }
         }
 
-        // Potential rebalance
-        if path.len() > self.alpha_balance_depth(self.max_size) {
-            if let Some(scapegoat_idx) = self.find_scapegoat(&path) {
-                self.rebuild::<U>(scapegoat_idx);
+        match self.opt_max_height {
+            // New node too deep, full rebuild (see `set_max_height`)
+            Some(max) if opt_val.is_none() && (path.len() + 1 > max) => {
+                assert!(
+                    optimal_height(self.len()) <= max,
+                    "Max height ({}) exceeded!",
+                    max
+                );
+                self.rebalance();
+            }
+            // Potential rebalance
+            _ => {
+                if path.len() > self.alpha_balance_depth(self.max_size) {
+                    if let Some(scapegoat_idx) = self.find_scapegoat(&path) {
+                        self.rebuild::<U>(scapegoat_idx);
+                    }
+                }
             }
         }
 
@@ -1165,6 +1822,10 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
         let sorted_sub = self.flatten_subtree_to_sorted_idxs(idx);
         self.rebalance_subtree_from_sorted_idxs::<U>(idx, &sorted_sub);
         self.rebal_cnt = self.rebal_cnt.wrapping_add(1);
+
+        if let Some(cb) = self.opt_rebal_callback {
+            cb(idx, sorted_sub.len());
+        }
     }
 
     // Height re-balance of subtree (e.g. depth of the two subtrees of every node never differs by more than one).
@@ -1267,6 +1928,33 @@ impl<K: Ord + Default, V: Default, const N: usize> SgTree<K, V, N> {
     }
 }
 
+// Range Helpers -------------------------------------------------------------------------------------------------------
+
+// Compare a key to a range: `Less` if below the start bound, `Greater` if past the end bound, `Equal` if within.
+pub(super) fn cmp_to_range<Q, R>(key: &Q, range: &R) -> Ordering
+where
+    R: RangeBounds<Q>,
+    Q: Ord + ?Sized,
+{
+    let below_start = match range.start_bound() {
+        Bound::Included(start) => key < start,
+        Bound::Excluded(start) => key <= start,
+        Bound::Unbounded => false,
+    };
+
+    let past_end = match range.end_bound() {
+        Bound::Included(end) => key > end,
+        Bound::Excluded(end) => key >= end,
+        Bound::Unbounded => false,
+    };
+
+    match (below_start, past_end) {
+        (true, _) => Ordering::Less,
+        (false, true) => Ordering::Greater,
+        (false, false) => Ordering::Equal,
+    }
+}
+
 // Convenience Traits --------------------------------------------------------------------------------------------------
 
 // Debug
@@ -1275,8 +1963,72 @@ where
     K: Ord + Debug + Default,
     V: Debug + Default,
 {
+    /// Compact form (`{:?}`) lists entries as a map, in sorted order.
+    ///
+    /// Alternate form (`{:#?}`) renders tree structure for debugging, one node per line,
+    /// indented by depth and labeled as root (`T`), left (`L`), or right (`R`) child, with its arena index.
+    /// A node with only one child lists the missing one as `-`, so left and right stay unambiguous:
+    ///
+    /// ```text
+    /// SgTree (len: 3, root_idx: Some(0))
+    /// T: [0] 2 => "b"
+    ///     L: [1] 1 => "a"
+    ///     R: [2] 3 => "c"
+    /// ```
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_map().entries(self.iter()).finish()
+        // Recursion depth bounded by tree height, logarithmic in size
+        fn fmt_subtree<K, V, const N: usize>(
+            sgt: &SgTree<K, V, N>,
+            f: &mut fmt::Formatter<'_>,
+            label: &str,
+            opt_idx: Option<usize>,
+            depth: usize,
+        ) -> fmt::Result
+        where
+            K: Debug + Default,
+            V: Debug + Default,
+        {
+            let indent = depth * 4;
+            let idx = match opt_idx {
+                Some(idx) => idx,
+                None => return write!(f, "\n{:indent$}{}: -", "", label),
+            };
+
+            let node = &sgt.arena[idx];
+            write!(
+                f,
+                "\n{:indent$}{}: [{}] {:?} => {:?}",
+                "",
+                label,
+                idx,
+                node.key(),
+                node.val()
+            )?;
+
+            if node.left_idx().is_some() || node.right_idx().is_some() {
+                fmt_subtree(sgt, f, "L", node.left_idx(), depth + 1)?;
+                fmt_subtree(sgt, f, "R", node.right_idx(), depth + 1)?;
+            }
+
+            Ok(())
+        }
+
+        match f.alternate() {
+            true => {
+                write!(
+                    f,
+                    "SgTree (len: {}, root_idx: {:?})",
+                    self.len(),
+                    self.opt_root_idx
+                )?;
+
+                match self.opt_root_idx {
+                    Some(root_idx) => fmt_subtree(self, f, "T", Some(root_idx), 0),
+                    None => Ok(()),
+                }
+            }
+            false => f.debug_map().entries(self.iter()).finish(),
+        }
     }
 }
 
@@ -1432,6 +2184,7 @@ where
 // Iterators -----------------------------------------------------------------------------------------------------------
 
 // Construct from iterator.
+// Panics if capacity is exceeded, see `collect_checked` for a fallible alternative.
 impl<K, V, const N: usize> FromIterator<(K, V)> for SgTree<K, V, N>
 where
     K: Ord + Default,
@@ -1490,3 +2243,438 @@ where
         IntoIter::new(self)
     }
 }
+
+// Test ----------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::SgTree;
+    use core::cell::Cell;
+
+    const CAPACITY: usize = 128;
+
+    #[test]
+    fn test_clear_vs_clear_stats() {
+        // Sequential inserts force rebalancing
+        let mut sgt: SgTree<usize, usize, CAPACITY> = (0..CAPACITY).map(|i| (i, i)).collect();
+        let rebal_cnt = sgt.rebal_cnt();
+        assert!(rebal_cnt > 0);
+
+        sgt.clear();
+        assert!(sgt.is_empty());
+        assert_eq!(sgt.rebal_cnt(), rebal_cnt);
+
+        sgt.extend((0..CAPACITY).map(|i| (i, i)));
+        assert!(sgt.rebal_cnt() > rebal_cnt);
+
+        sgt.clear_stats();
+        assert!(sgt.is_empty());
+        assert_eq!(sgt.rebal_cnt(), 0);
+        assert_eq!(sgt.max_size, 0);
+
+        // Emptied by removal, stats still reset
+        sgt.extend((0..4).map(|i| (i, i)));
+        while sgt.pop_first().is_some() {}
+        assert!(sgt.max_size > 0);
+        sgt.clear_stats();
+        assert_eq!(sgt.max_size, 0);
+
+        // Maximum height is configuration, kept by both
+        sgt.set_max_height(Some(8));
+        sgt.extend((0..4).map(|i| (i, i)));
+        sgt.clear();
+        assert_eq!(sgt.max_height(), Some(8));
+        sgt.extend((0..4).map(|i| (i, i)));
+        sgt.clear_stats();
+        assert_eq!(sgt.max_height(), Some(8));
+    }
+
+    #[test]
+    fn test_rebal_cnt_wrap_and_reset() {
+        let mut sgt: SgTree<usize, usize, CAPACITY> = (0..CAPACITY).map(|i| (i, i)).collect();
+        let max_size = sgt.max_size;
+
+        // Wraps rather than overflowing
+        sgt.rebal_cnt = usize::MAX - 1;
+        sgt.rebalance();
+        assert_eq!(sgt.rebal_cnt(), usize::MAX);
+        sgt.rebalance();
+        assert_eq!(sgt.rebal_cnt(), 0);
+        sgt.rebalance();
+        assert_eq!(sgt.rebal_cnt(), 1);
+
+        // Reset keeps contents and max size
+        sgt.reset_rebal_cnt();
+        assert_eq!(sgt.rebal_cnt(), 0);
+        assert_eq!(sgt.len(), CAPACITY);
+        assert_eq!(sgt.max_size, max_size);
+        sgt.assert_invariants();
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_to_dot() {
+        let mut sgt = SgTree::<&str, usize, CAPACITY>::new();
+        assert_eq!(sgt.to_dot(), "digraph SgTree {\n}\n");
+
+        sgt.insert("b", 0);
+        sgt.insert("a", 1);
+        sgt.insert("c", 2);
+        sgt.insert("d\"", 3);
+
+        let dot = sgt.to_dot();
+        assert!(dot.starts_with("digraph SgTree {\n"));
+        assert!(dot.ends_with("}\n"));
+        for label in [
+            r#"label="\"a\"""#,
+            r#"label="\"b\"""#,
+            r#"label="\"c\"""#,
+            r#"label="\"d\\\"\"""#,
+        ] {
+            assert_eq!(dot.matches(label).count(), 1, "{}", label);
+        }
+
+        // One edge per non-root node
+        assert_eq!(dot.matches(" -> ").count(), sgt.len() - 1);
+        assert_eq!(dot.matches(r#"[label="L"]"#).count(), 1);
+        assert_eq!(dot.matches(r#"[label="R"]"#).count(), 2);
+    }
+
+    #[test]
+    fn test_rebalance_callback() {
+        thread_local! {
+            static CALLBACK_CNT: Cell<usize> = const { Cell::new(0) };
+        }
+
+        fn count_rebuild(_scapegoat_idx: usize, subtree_size: usize) {
+            assert!(subtree_size > 0);
+            CALLBACK_CNT.with(|cnt| cnt.set(cnt.get() + 1));
+        }
+
+        let mut sgt = SgTree::<usize, usize, CAPACITY>::new();
+        sgt.set_rebalance_callback(count_rebuild);
+
+        // Sorted order, worst case
+        sgt.extend((0..CAPACITY).map(|i| (i, i)));
+        assert!(sgt.rebal_cnt() > 0);
+        assert_eq!(CALLBACK_CNT.with(Cell::get), sgt.rebal_cnt());
+
+        // Rebuilds on removal too
+        (0..(CAPACITY - 1)).for_each(|i| {
+            sgt.remove(&i);
+        });
+        assert_eq!(CALLBACK_CNT.with(Cell::get), sgt.rebal_cnt());
+
+        // Kept across clears
+        sgt.clear();
+        sgt.extend((0..CAPACITY).map(|i| (i, i)));
+        assert_eq!(CALLBACK_CNT.with(Cell::get), sgt.rebal_cnt());
+
+        sgt.clear_stats();
+        CALLBACK_CNT.with(|cnt| cnt.set(0));
+        sgt.insert(0, 0);
+        sgt.rebalance();
+        assert_eq!(CALLBACK_CNT.with(Cell::get), 1);
+
+        // Kept by each tree when appending into an empty one
+        sgt.opt_rebal_callback = None;
+        let mut empty = SgTree::<usize, usize, CAPACITY>::new();
+        empty.set_rebalance_callback(count_rebuild);
+        empty.append(&mut sgt);
+        empty.rebalance();
+        assert_eq!(CALLBACK_CNT.with(Cell::get), 2);
+        assert!(sgt.opt_rebal_callback.is_none());
+    }
+
+    #[test]
+    fn test_get_out_of_bounds() {
+        use core::cmp::Ordering;
+
+        thread_local! {
+            static CMP_CNT: Cell<usize> = const { Cell::new(0) };
+        }
+
+        // Counts comparisons, to observe descent
+        #[derive(Default, PartialEq, Eq)]
+        struct CountedKey(usize);
+
+        impl Ord for CountedKey {
+            fn cmp(&self, other: &Self) -> Ordering {
+                CMP_CNT.with(|cnt| cnt.set(cnt.get() + 1));
+                self.0.cmp(&other.0)
+            }
+        }
+
+        impl PartialOrd for CountedKey {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        let cmp_cnt = |f: &dyn Fn()| {
+            CMP_CNT.with(|cnt| cnt.set(0));
+            f();
+            CMP_CNT.with(Cell::get)
+        };
+
+        let mut sgt: SgTree<CountedKey, usize, CAPACITY> =
+            (10..(10 + CAPACITY)).map(|i| (CountedKey(i), i)).collect();
+        let (min, max) = (CountedKey(10), CountedKey(9 + CAPACITY));
+
+        // Out of bounds, short-circuits
+        assert_eq!(cmp_cnt(&|| assert!(sgt.get(&CountedKey(0)).is_none())), 1);
+        assert_eq!(
+            cmp_cnt(&|| assert!(sgt.get(&CountedKey(usize::MAX)).is_none())),
+            2
+        );
+        assert!(sgt.get_mut(&CountedKey(usize::MAX)).is_none());
+
+        // In bounds, full descent
+        assert!(cmp_cnt(&|| assert_eq!(sgt.get(&min), Some(&10))) > 2);
+        assert!(cmp_cnt(&|| assert_eq!(sgt.get(&max), Some(&(9 + CAPACITY)))) > 2);
+        *sgt.get_mut(&max).unwrap() = 0;
+        assert_eq!(sgt.get(&max), Some(&0));
+
+        // Bounds track mutation
+        sgt.pop_first();
+        assert!(sgt.get(&min).is_none());
+        assert_eq!(sgt.get(&CountedKey(11)), Some(&11));
+
+        // Borrowed form
+        let sgt: SgTree<String, usize, CAPACITY> = ["b", "c", "d"]
+            .into_iter()
+            .map(|s| (s.to_string(), 0))
+            .collect();
+        assert!(sgt.get("a").is_none());
+        assert!(sgt.get("e").is_none());
+        assert!(sgt.contains_key("b") && sgt.contains_key("d"));
+
+        let empty = SgTree::<String, usize, CAPACITY>::new();
+        assert!(empty.get("a").is_none());
+    }
+
+    #[test]
+    fn test_max_height() {
+        use crate::SgError;
+
+        const MAX_HEIGHT: usize = 7;
+        const MAX_LEN: usize = (1 << MAX_HEIGHT) - 1;
+
+        let mut sgt = SgTree::<usize, usize, CAPACITY>::new();
+        sgt.set_rebal_param(0.99, 1.0).unwrap(); // Nearly unbalanced allowed
+        sgt.set_max_height(Some(MAX_HEIGHT));
+        assert_eq!(sgt.max_height(), Some(MAX_HEIGHT));
+
+        // Sorted order, worst case
+        for i in 0..MAX_LEN {
+            sgt.insert(i, i);
+            assert!(sgt.height() <= MAX_HEIGHT);
+        }
+        sgt.assert_invariants();
+
+        // Even a balanced tree wouldn't fit, unchanged
+        assert_eq!(
+            sgt.try_insert(MAX_LEN, 0),
+            Err(SgError::MaximumHeightExceeded)
+        );
+        assert_eq!(
+            sgt.try_extend([(MAX_LEN, 0)].into_iter()),
+            Err(SgError::MaximumHeightExceeded)
+        );
+        assert_eq!(
+            sgt.try_insert_sorted([(MAX_LEN, 0)].into_iter()),
+            Err(SgError::MaximumHeightExceeded)
+        );
+        assert_eq!(sgt.len(), MAX_LEN);
+
+        // Updates don't grow the tree
+        assert_eq!(sgt.try_insert(0, 1), Ok(Some(0)));
+
+        // No limit
+        sgt.set_max_height(None);
+        assert!(sgt.try_insert(MAX_LEN, 0).is_ok());
+
+        // New limit applied to an existing tree
+        let mut sgt = SgTree::<usize, usize, CAPACITY>::new();
+        sgt.set_rebal_param(0.99, 1.0).unwrap();
+        sgt.extend((0..32).map(|i| (i, i)));
+        assert!(sgt.height() > 6);
+
+        sgt.set_max_height(Some(6));
+        assert!(sgt.height() <= 6);
+
+        // Kept when appending into an empty tree
+        let mut empty = SgTree::<usize, usize, CAPACITY>::new();
+        empty.set_max_height(Some(6));
+        empty.append(&mut sgt);
+        assert_eq!(empty.max_height(), Some(6));
+        assert_eq!(empty.len(), 32);
+        assert!(empty.height() <= 6);
+    }
+
+    #[test]
+    #[should_panic(expected = "Max height (3) exceeded!")]
+    fn test_max_height_overflow() {
+        let mut sgt = SgTree::<usize, usize, CAPACITY>::new();
+        sgt.set_max_height(Some(3));
+        (0..8).for_each(|i| {
+            sgt.insert(i, i);
+        });
+    }
+
+    #[test]
+    fn test_rebuild_balanced() {
+        let mut sgt = SgTree::<usize, usize, CAPACITY>::new();
+        sgt.set_rebal_param(0.9, 1.0).unwrap(); // Rarely rebalances
+
+        // Churn: sorted inserts, then removals
+        sgt.extend((0..CAPACITY).map(|i| (i, i)));
+        (0..(CAPACITY / 2)).for_each(|_| {
+            sgt.pop_first();
+        });
+        let rebal_cnt = sgt.rebal_cnt();
+        assert!(sgt.arena_stats().holes > 0);
+        assert!(sgt.max_size > sgt.len());
+
+        sgt.rebuild_balanced();
+        assert_eq!(sgt.rebal_param(), (0.9, 1.0));
+        assert_eq!(sgt.rebal_cnt(), rebal_cnt + 1);
+        assert_eq!(sgt.arena_stats().holes, 0);
+        assert_eq!(sgt.max_size, sgt.len());
+        sgt.assert_invariants();
+
+        // Theoretical minimum, ceil(log2(n + 1))
+        let min_height = (usize::BITS - sgt.len().leading_zeros()) as usize;
+        assert_eq!(sgt.height(), min_height);
+        assert!(sgt.iter().map(|(k, _)| *k).eq((CAPACITY / 2)..CAPACITY));
+
+        // No-op for empty
+        let mut empty = SgTree::<usize, usize, CAPACITY>::new();
+        empty.rebuild_balanced();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_suggest_alpha() {
+        use rand::{rngs::SmallRng, seq::SliceRandom, SeedableRng};
+
+        let default_alpha = {
+            let (num, denom) = SgTree::<usize, usize, CAPACITY>::new().rebal_param();
+            num / denom
+        };
+        let to_alpha = |(num, denom): (f32, f32)| num / denom;
+
+        // No workload observed, keeps current
+        let mut sgt = SgTree::<usize, usize, CAPACITY>::new();
+        assert_eq!(to_alpha(sgt.suggest_alpha()), default_alpha);
+
+        // Sorted order, insert-heavy
+        sgt.extend((0..CAPACITY).map(|i| (i, i)));
+        let insert_heavy_alpha = to_alpha(sgt.suggest_alpha());
+        assert!(insert_heavy_alpha > default_alpha);
+        assert!(insert_heavy_alpha < 1.0);
+
+        sgt.auto_tune_alpha();
+        assert_eq!(to_alpha(sgt.rebal_param()), insert_heavy_alpha);
+
+        // Random order, few rebuilds
+        let mut keys: Vec<usize> = (0..CAPACITY).collect();
+        keys.shuffle(&mut SmallRng::seed_from_u64(0));
+
+        let sgt: SgTree<usize, usize, CAPACITY> = keys.into_iter().map(|i| (i, i)).collect();
+        let read_heavy_alpha = to_alpha(sgt.suggest_alpha());
+        assert!(read_heavy_alpha < default_alpha);
+        assert!(read_heavy_alpha >= 0.5);
+    }
+
+    #[test]
+    fn test_rebalance() {
+        let mut sgt = SgTree::<usize, usize, CAPACITY>::new();
+        sgt.set_rebal_param(0.99, 1.0).unwrap(); // Nearly unbalanced allowed
+
+        // Sorted order, worst case
+        sgt.extend((0..(CAPACITY - 1)).map(|i| (i, i)));
+        let rebal_cnt = sgt.rebal_cnt();
+        assert!(sgt.height() > 7);
+
+        sgt.rebalance();
+        assert_eq!(sgt.rebal_cnt(), rebal_cnt + 1);
+        sgt.assert_invariants();
+
+        // Theoretical minimum, ceil(log2(n + 1))
+        let min_height = (usize::BITS - sgt.len().leading_zeros()) as usize;
+        assert_eq!(min_height, 7);
+        assert_eq!(sgt.height(), min_height);
+    }
+
+    #[test]
+    fn test_max_capacity_idx() {
+        const MAX_CAPACITY: usize = u16::MAX as usize;
+        const ITEM_CNT: usize = 2048;
+
+        // Scratch space for rebuilds is `N`-sized, run with a big enough stack
+        let test = || {
+            let mut sgt = SgTree::<usize, (), MAX_CAPACITY>::new();
+            assert_eq!(
+                sgt.capacity(),
+                SgTree::<usize, (), MAX_CAPACITY>::max_capacity()
+            );
+
+            // Unordered keys, spread across the full `u16` range
+            let keys = (0..ITEM_CNT).map(|i| (i * 7919) % MAX_CAPACITY);
+            for k in keys.clone() {
+                assert_eq!(sgt.try_insert(k, ()), Ok(None));
+            }
+            assert_eq!(sgt.len(), ITEM_CNT);
+            sgt.assert_invariants();
+
+            for _ in 0..(ITEM_CNT / 4) {
+                assert!(sgt.pop_first().is_some());
+                assert!(sgt.pop_last().is_some());
+            }
+            assert_eq!(sgt.len(), ITEM_CNT / 2);
+            sgt.assert_invariants();
+
+            // Refill freed slots
+            for k in keys {
+                assert!(sgt.try_insert(k, ()).is_ok());
+            }
+            assert_eq!(sgt.len(), ITEM_CNT);
+            sgt.assert_invariants();
+        };
+
+        std::thread::Builder::new()
+            .stack_size(256 * 1024 * 1024)
+            .spawn(test)
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    #[test]
+    fn test_debug_alternate() {
+        let mut sgt = SgTree::<usize, &str, CAPACITY>::new();
+        assert_eq!(format!("{:#?}", sgt), "SgTree (len: 0, root_idx: None)");
+
+        sgt.insert(2, "b");
+        sgt.insert(1, "a");
+        sgt.insert(3, "c");
+        sgt.insert(4, "d");
+
+        // Compact form unchanged
+        assert_eq!(format!("{:?}", sgt), r#"{1: "a", 2: "b", 3: "c", 4: "d"}"#);
+
+        // Structure, missing left child shown
+        assert_eq!(
+            format!("{:#?}", sgt),
+            concat!(
+                "SgTree (len: 4, root_idx: Some(0))\n",
+                "T: [0] 2 => \"b\"\n",
+                "    L: [1] 1 => \"a\"\n",
+                "    R: [2] 3 => \"c\"\n",
+                "        L: -\n",
+                "        R: [3] 4 => \"d\"",
+            )
+        );
+    }
+}
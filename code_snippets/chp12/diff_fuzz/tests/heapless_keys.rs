@@ -0,0 +1,38 @@
+// Embedded use case: fixed-capacity `heapless::String` keys, looked up by `&str` via `Borrow<str>`.
+
+use buggy_scapegoat::{SgMap, SgSet};
+use heapless::String;
+
+const KEY_LEN: usize = 32;
+const CAPACITY: usize = 10;
+
+type Key = String<KEY_LEN>;
+
+fn key(s: &str) -> Key {
+    Key::try_from(s).unwrap()
+}
+
+#[test]
+fn test_heapless_map_str_lookup() {
+    let mut map = SgMap::<Key, u32, CAPACITY>::new();
+    map.insert(key("abc"), 1);
+    map.insert(key("def"), 2);
+
+    assert_eq!(map.get("abc"), Some(&1));
+    assert_eq!(map["def"], 2);
+    assert!(map.contains_key("def"));
+    assert_eq!(map.get("xyz"), None);
+
+    *map.get_mut("abc").unwrap() += 10;
+    assert_eq!(map.remove("abc"), Some(11));
+    assert!(!map.contains_key("abc"));
+}
+
+#[test]
+fn test_heapless_set_str_lookup() {
+    let set: SgSet<Key, CAPACITY> = ["b", "a", "c"].into_iter().map(key).collect();
+
+    assert!(set.contains("a"));
+    assert_eq!(set.get("c").map(|k| k.as_str()), Some("c"));
+    assert!(set.iter().map(|k| k.as_str()).eq(["a", "b", "c"]));
+}
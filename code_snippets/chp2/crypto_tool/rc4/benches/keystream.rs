@@ -0,0 +1,67 @@
+// Compares bulk keystream generation via `fill_keystream` against a loop of `prga_next` calls.
+//
+// Run with `cargo bench -p rc4 --bench keystream`.
+//
+// Methodology:
+//
+// * Both variants start from a fresh cipher with the same 128-bit key, so they produce identical output.
+// * Each buffer size is warmed up, then sampled `SAMPLES` times. The median sample is reported, divided by the
+//   buffer length to give nanoseconds per byte.
+// * Cipher construction (key scheduling) is excluded from timing.
+// * `black_box` keeps the optimizer from eliding work whose result is otherwise unused.
+//
+// Output is CSV on stdout (`impl,buf_len,ns_per_byte`), one row per measurement.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use rc4::Rc4;
+
+const SAMPLES: usize = 51;
+const WARMUP: usize = 5;
+const KEY: [u8; 16] = [
+    0x4b, 0x8e, 0x29, 0x87, 0x80, 0x95, 0x96, 0xa3, 0xbb, 0x23, 0x82, 0x49, 0x9f, 0x1c, 0xe7, 0xc2,
+];
+
+// Time `op` on a fresh cipher repeatedly, returning the median sample.
+fn median_time<F: FnMut(&mut Rc4, &mut [u8])>(buf: &mut [u8], mut op: F) -> Duration {
+    for _ in 0..WARMUP {
+        op(&mut Rc4::new(&KEY), buf);
+    }
+
+    let mut samples: Vec<Duration> = (0..SAMPLES)
+        .map(|_| {
+            let mut rc4 = Rc4::new(&KEY);
+            let start = Instant::now();
+            op(&mut rc4, buf);
+            black_box(&buf);
+            start.elapsed()
+        })
+        .collect();
+
+    samples.sort_unstable();
+    samples[SAMPLES / 2]
+}
+
+fn report(name: &str, buf_len: usize, elapsed: Duration) {
+    let ns_per_byte = elapsed.as_nanos() as f64 / buf_len as f64;
+    println!("{name},{buf_len},{ns_per_byte:.3}");
+}
+
+fn main() {
+    println!("impl,buf_len,ns_per_byte");
+
+    for buf_len in [1 << 10, 1 << 16, 1 << 20] {
+        let mut buf = vec![0; buf_len];
+
+        let elapsed = median_time(&mut buf, |rc4, buf| {
+            for b in buf.iter_mut() {
+                *b = rc4.prga_next();
+            }
+        });
+        report("prga_next", buf_len, elapsed);
+
+        let elapsed = median_time(&mut buf, |rc4, buf| rc4.fill_keystream(buf));
+        report("fill_keystream", buf_len, elapsed);
+    }
+}
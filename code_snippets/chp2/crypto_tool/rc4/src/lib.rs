@@ -10,6 +10,17 @@ pub struct Rc4 {
 }
 // ANCHOR_END: Rc4
 
+/// Snapshot of [`Rc4`] cipher state, for checkpointing a keystream.
+///
+/// **Security:** the state is as sensitive as the key.
+/// Anyone holding it can reproduce the rest of the keystream, and thus decrypt subsequent data.
+#[derive(Debug, Clone, Copy)]
+pub struct Rc4State {
+    s: [u8; 256],
+    i: u8,
+    j: u8,
+}
+
 impl Rc4 {
     // ANCHOR: new
     /// Init a new Rc4 stream cipher instance
@@ -45,6 +56,34 @@ impl Rc4 {
     }
     // ANCHOR_END: new
 
+    /// Init a new Rc4 stream cipher instance from a fixed-size key.
+    /// Key length is validated at compile time: arrays outside 5 to 256 bytes (40 to 2048 bits) fail to build.
+    pub fn new_const<const K: usize>(key: &[u8; K]) -> Self {
+        const {
+            assert!(5 <= K && K <= 256, "RC4 key must be 5 to 256 bytes");
+        }
+
+        Rc4::new(key)
+    }
+
+    /// Init a new Rc4 stream cipher instance with a 40-bit key (RC4-40).
+    /// Fixed-size array guarantees valid key length at compile time.
+    pub fn new_rc4_40(key: &[u8; 5]) -> Self {
+        Rc4::new(key)
+    }
+
+    /// Init a new Rc4 stream cipher instance with a 128-bit key (RC4-128).
+    /// Fixed-size array guarantees valid key length at compile time.
+    pub fn new_rc4_128(key: &[u8; 16]) -> Self {
+        Rc4::new(key)
+    }
+
+    /// Init a new Rc4 stream cipher instance with a 256-bit key (RC4-256).
+    /// Fixed-size array guarantees valid key length at compile time.
+    pub fn new_rc4_256(key: &[u8; 32]) -> Self {
+        Rc4::new(key)
+    }
+
     // ANCHOR: apply_keystream
     /// Stateful, in-place en/decryption (current keystream XORed with data).
     /// Use if plaintext/ciphertext is transmitted in chunks.
@@ -97,6 +136,64 @@ impl Rc4 {
         self.s[(self.s[self.i as usize].wrapping_add(self.s[self.j as usize])) as usize]
     }
     // ANCHOR_END: prga_next
+
+    /// Overwrite `buf` with the next `buf.len()` bytes of the keystream.
+    /// Equivalent to [`apply_keystream`](Rc4::apply_keystream) on a zeroed buffer, but faster for bulk output:
+    /// indices are kept in locals and the swap is done inline, so the loop doesn't round-trip through `self`.
+    pub fn fill_keystream(&mut self, buf: &mut [u8]) {
+        let (mut i, mut j) = (self.i, self.j);
+        let s = &mut self.s;
+
+        for b_ptr in buf {
+            i = i.wrapping_add(1);
+            let s_i = s[i as usize];
+            j = j.wrapping_add(s_i);
+            let s_j = s[j as usize];
+
+            // Swap values of s[i] and s[j]
+            s[i as usize] = s_j;
+            s[j as usize] = s_i;
+
+            *b_ptr = s[s_i.wrapping_add(s_j) as usize];
+        }
+
+        self.i = i;
+        self.j = j;
+    }
+
+    /// Write the next `n` bytes of the keystream as lowercase hex, without allocating (for debugging).
+    /// Advances the keystream.
+    pub fn write_keystream_hex<W: core::fmt::Write>(
+        &mut self,
+        w: &mut W,
+        n: usize,
+    ) -> core::fmt::Result {
+        for _ in 0..n {
+            write!(w, "{:02x}", self.prga_next())?;
+        }
+
+        Ok(())
+    }
+
+    /// Snapshot current cipher state, e.g. to checkpoint a long-running stream.
+    /// See [`Rc4State`] for security implications.
+    pub fn to_state(&self) -> Rc4State {
+        Rc4State {
+            s: self.s,
+            i: self.i,
+            j: self.j,
+        }
+    }
+
+    /// Restore a cipher instance from a [`to_state`](Rc4::to_state) snapshot.
+    /// Keystream output resumes from the snapshot point.
+    pub fn from_state(state: Rc4State) -> Self {
+        Rc4 {
+            s: state.s,
+            i: state.i,
+            j: state.j,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -221,4 +318,126 @@ mod tests {
         assert_eq!(out_buf[256..272], test_stream_256);
     }
     // ANCHOR_END: ietf
+
+    // See: https://datatracker.ietf.org/doc/html/rfc6229#section-2
+    #[test]
+    fn keystream_hex_dump() {
+        let key: [u8; 5] = [0x01, 0x02, 0x03, 0x04, 0x5];
+        let mut rc4 = Rc4::new(&key);
+        let mut hex = String::new();
+
+        rc4.write_keystream_hex(&mut hex, 8).unwrap();
+        assert_eq!(hex, "b2396305f03dc027");
+
+        // Keystream advanced
+        rc4.write_keystream_hex(&mut hex, 8).unwrap();
+        assert_eq!(hex, "b2396305f03dc027ccc3524a0a1118a8");
+    }
+
+    // Fixed-capacity `fmt::Write` sink, no allocation
+    struct FixedHexBuf {
+        buf: [u8; 16],
+        len: usize,
+    }
+
+    impl core::fmt::Write for FixedHexBuf {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            let end = self.len + s.len();
+            if end > self.buf.len() {
+                return Err(core::fmt::Error);
+            }
+
+            self.buf[self.len..end].copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    // See: https://datatracker.ietf.org/doc/html/rfc6229#section-2
+    #[test]
+    fn keystream_hex_dump_fixed_capacity() {
+        let key: [u8; 5] = [0x01, 0x02, 0x03, 0x04, 0x5];
+        let mut rc4 = Rc4::new(&key);
+        let mut hex = FixedHexBuf {
+            buf: [0; 16],
+            len: 0,
+        };
+
+        rc4.write_keystream_hex(&mut hex, 8).unwrap();
+        assert_eq!(&hex.buf[..hex.len], b"b2396305f03dc027");
+
+        // Full, error and no partial write
+        assert_eq!(rc4.write_keystream_hex(&mut hex, 1), Err(core::fmt::Error));
+        assert_eq!(&hex.buf[..hex.len], b"b2396305f03dc027");
+    }
+
+    #[test]
+    fn fixed_size_key_constructors() {
+        let key_40: [u8; 5] = core::array::from_fn(|i| (i + 1) as u8);
+        let key_128: [u8; 16] = core::array::from_fn(|i| (i + 1) as u8);
+        let key_256: [u8; 32] = core::array::from_fn(|i| (i + 1) as u8);
+
+        assert_eq!(Rc4::new_rc4_40(&key_40).prga_next(), 0xb2);
+        assert_eq!(Rc4::new_rc4_128(&key_128).prga_next(), 0x9a);
+        assert_eq!(Rc4::new_rc4_256(&key_256).prga_next(), 0xea);
+    }
+
+    #[test]
+    fn state_checkpoint_restore() {
+        let key: [u8; 5] = [0x01, 0x02, 0x03, 0x04, 0x5];
+        let mut rc4 = Rc4::new(&key);
+
+        // Advance mid-stream, then checkpoint
+        let mut skip = [0; 100];
+        rc4.apply_keystream(&mut skip);
+        let checkpoint = rc4.to_state();
+
+        // Process more
+        let mut first_pass = [0xaa; 64];
+        rc4.apply_keystream(&mut first_pass);
+
+        // Restore and reprocess
+        let mut restored = Rc4::from_state(checkpoint);
+        let mut second_pass = [0xaa; 64];
+        restored.apply_keystream(&mut second_pass);
+        assert_eq!(first_pass, second_pass);
+
+        // Both instances continue in lockstep
+        assert_eq!(rc4.prga_next(), restored.prga_next());
+    }
+
+    #[test]
+    fn const_key_constructor() {
+        let key: [u8; 16] = core::array::from_fn(|i| (i + 1) as u8);
+        let mut rc4 = Rc4::new_const(&key);
+        let mut rc4_expected = Rc4::new(&key);
+
+        let mut out_buf = [0; 32];
+        let mut out_buf_expected = [0; 32];
+        rc4.apply_keystream(&mut out_buf);
+        rc4_expected.apply_keystream(&mut out_buf_expected);
+
+        assert_eq!(out_buf[0], 0x9a); // RFC 6229, 128-bit key
+        assert_eq!(out_buf, out_buf_expected);
+    }
+
+    #[test]
+    fn fill_keystream_matches_apply() {
+        let key: [u8; 5] = [0x01, 0x02, 0x03, 0x04, 0x5];
+        let mut rc4_fill = Rc4::new(&key);
+        let mut rc4_apply = Rc4::new(&key);
+
+        // Uneven chunk sizes, including empty
+        for len in [0, 1, 15, 256, 1000] {
+            let mut filled = vec![0xff; len];
+            let mut applied = vec![0; len];
+
+            rc4_fill.fill_keystream(&mut filled);
+            rc4_apply.apply_keystream(&mut applied);
+            assert_eq!(filled, applied);
+        }
+
+        // Still in lockstep
+        assert_eq!(rc4_fill.prga_next(), rc4_apply.prga_next());
+    }
 }
@@ -0,0 +1,9 @@
+// Key length validation for `Rc4::new_const` is a post-monomorphization const assertion.
+// Having a `pass` case makes `trybuild` fully build (not just check) every case, so the assertion fires.
+// The `.stderr` snapshot is compiler-version specific, hence the pinned `rust-toolchain.toml`.
+#[test]
+fn new_const_key_len() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/new_const_16_byte_key.rs");
+    t.compile_fail("tests/ui/new_const_4_byte_key.rs");
+}
@@ -0,0 +1,10 @@
+use rc4::Rc4;
+
+fn main() {
+    let key: [u8; 16] = [0x01; 16];
+    let mut msg = *b"Hello World!";
+
+    Rc4::new_const(&key).apply_keystream(&mut msg);
+    Rc4::new_const(&key).apply_keystream(&mut msg);
+    assert_eq!(&msg, b"Hello World!");
+}
@@ -0,0 +1,6 @@
+use rc4::Rc4;
+
+fn main() {
+    let key: [u8; 4] = [0x01, 0x02, 0x03, 0x04];
+    let _rc4 = Rc4::new_const(&key);
+}
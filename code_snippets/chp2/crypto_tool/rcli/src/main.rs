@@ -1,3 +1,5 @@
+#![forbid(unsafe_code)]
+
 // ANCHOR: full_imports
 use clap::Parser;
 use rc4::Rc4;
@@ -22,9 +24,34 @@ struct Args {
         num_args = 5..=256,
     )]
     key: Vec<String>,
+
+    /// Print parsed key details and a security warning
+    #[arg(long)]
+    explain: bool,
 }
 // ANCHOR_END: clap_args
 
+// ANCHOR: explain_fn
+const RC4_WARNING: &str =
+    "WARNING: RC4 is cryptographically broken, do not use it to protect real secrets!";
+
+/// Human-readable summary of the parsed key, for educational use
+fn explain(key_bytes: &[u8]) -> String {
+    let key_hex = key_bytes
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect::<Vec<String>>()
+        .join(" ");
+
+    format!(
+        "Key bytes: {}\nKey length: {} bits\n{}",
+        key_hex,
+        key_bytes.len() * 8,
+        RC4_WARNING
+    )
+}
+// ANCHOR_END: explain_fn
+
 // ANCHOR: cli_main
 fn main() -> std::io::Result<()> {
     let args = Args::parse();
@@ -41,6 +68,10 @@ fn main() -> std::io::Result<()> {
     // Validation note:
     // `Args` enforces (5 <= key_bytes.len() && key_bytes.len() <= 256)
 
+    if args.explain {
+        println!("{}", explain(&key_bytes));
+    }
+
     // Open the file for both reading and writing
     let mut file = File::options().read(true).write(true).open(&args.file)?;
 
@@ -61,3 +92,18 @@ fn main() -> std::io::Result<()> {
     Ok(())
 }
 // ANCHOR_END: cli_main
+
+#[cfg(test)]
+mod tests {
+    use super::{explain, RC4_WARNING};
+
+    #[test]
+    fn explain_output() {
+        let key_bytes = [0x4b, 0x8e, 0x29, 0x87, 0x80];
+        let output = explain(&key_bytes);
+
+        assert!(output.contains(RC4_WARNING));
+        assert!(output.contains("Key bytes: 4b 8e 29 87 80"));
+        assert!(output.contains("Key length: 40 bits"));
+    }
+}
@@ -1,3 +1,5 @@
+#![forbid(unsafe_code)]
+
 // ANCHOR: prime_test
 
 //! This library does unoptimized primality testing.
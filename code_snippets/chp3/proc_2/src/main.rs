@@ -1,3 +1,5 @@
+#![forbid(unsafe_code)]
+
 // ANCHOR: detailed_state
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub enum StopKind {
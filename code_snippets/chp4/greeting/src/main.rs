@@ -1,3 +1,5 @@
+#![forbid(unsafe_code)]
+
 fn get_greeting() -> String {
     String::from("Hello")
 }
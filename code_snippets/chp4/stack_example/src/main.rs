@@ -1,3 +1,5 @@
+#![forbid(unsafe_code)]
+
 // ANCHOR: stack_example
 // ANCHOR: recursive_count_down
 #[inline(never)]
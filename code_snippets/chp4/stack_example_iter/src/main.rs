@@ -1,3 +1,5 @@
+#![forbid(unsafe_code)]
+
 // ANCHOR: iterative_count_down
 #[inline(never)]
 fn iterative_count_down(x: usize) {
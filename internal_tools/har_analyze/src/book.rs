@@ -1,21 +1,23 @@
 use crate::{
     chapter::Chapter,
     content::Content,
-    lint::{Level, Linter, LinterBuilder},
+    lint::{Level, LeveledLintError, Linter, LinterBuilder},
     rules::*,
     traits::{GetChapter, GetMetrics},
     update::META_TAGS,
-    BOOK_SRC_DIR_RELATIVE, WORDS_PER_PAGE,
+    BOOK_SRC_DIR_RELATIVE, WORDS_PER_PAGE_DEFAULT,
 };
 
 use std::{
     cmp::Reverse,
-    collections::BTreeMap,
+    collections::{BTreeMap, BTreeSet},
     error::Error,
     ffi::OsStr,
     fmt,
-    fs::File,
+    fs::{self, File},
     io::{prelude::*, BufReader},
+    num::NonZeroUsize,
+    path::{Component, Path, PathBuf},
 };
 
 use colored::*;
@@ -26,11 +28,17 @@ use walkdir::WalkDir;
 
 const NON_CHP_NUM: usize = 0;
 const APPENDIX_CHP_NUM: usize = 16;
+const SUMMARY_FILE_NAME: &str = "SUMMARY.md";
+const WORD_REGEX: &str = r"([a-zA-Z']+)";
 
 /// Displayable book data model
 pub struct Book {
     /// Chapters by number
     pub chapters: BTreeMap<usize, Chapter>,
+    words_per_page: usize,
+    src_dir: PathBuf,
+    summary_chp_nums: Vec<usize>,
+    collect_section_data: bool,
 }
 
 impl GetMetrics for Book {
@@ -41,43 +49,124 @@ impl GetMetrics for Book {
     fn get_diagram_count(&self) -> usize {
         self.chapters.values().map(|c| c.get_diagram_count()).sum()
     }
+
+    fn get_section_breakdown(&self) -> Vec<(PathBuf, usize)> {
+        self.chapters
+            .values()
+            .flat_map(|c| c.get_section_breakdown())
+            .collect()
+    }
 }
 
 impl Book {
     /// Construct a book data model
     pub fn try_new(collect_section_data: bool) -> Result<Self, Box<dyn Error>> {
-        let word_regex = Regex::new(r"([a-zA-Z']+)")?;
-        let contents = Self::collect_contents(collect_section_data, &word_regex);
-        let mut chapters = BTreeMap::<usize, Chapter>::new();
-
-        contents.into_iter().for_each(|content| {
-            if let Some(number) = content.get_chp() {
-                match chapters.get_mut(&number) {
-                    Some(chp) => chp.contents.push(content),
-                    None => {
-                        chapters.insert(
-                            number,
-                            Chapter {
-                                contents: vec![content],
-                                number,
-                            },
-                        );
-                    }
-                }
+        Self::try_new_in(BOOK_SRC_DIR_RELATIVE, collect_section_data)
+    }
+
+    /// Construct a book data model from an arbitrary mdbook source directory
+    pub fn try_new_in(
+        src_dir: impl AsRef<Path>,
+        collect_section_data: bool,
+    ) -> Result<Self, Box<dyn Error>> {
+        let word_regex = Regex::new(WORD_REGEX)?;
+        let contents = Self::collect_contents(src_dir.as_ref(), collect_section_data, &word_regex);
+
+        let mut book = Book {
+            chapters: BTreeMap::new(),
+            words_per_page: WORDS_PER_PAGE_DEFAULT,
+            src_dir: src_dir.as_ref().to_path_buf(),
+            summary_chp_nums: Self::parse_summary_chp_nums(src_dir.as_ref())?,
+            collect_section_data,
+        };
+
+        contents
+            .into_iter()
+            .for_each(|content| book.insert_content(content));
+        book.sort_chapter_contents();
+
+        Ok(book)
+    }
+
+    /// Re-read specific files (e.g. changed on disk), leaving all other contents as-is.
+    /// Paths no longer on disk are removed, new paths are added.
+    /// Cheaper than [`Book::try_new_in`] when only a few files changed.
+    pub fn refresh(&mut self, paths: &[PathBuf]) -> Result<(), Box<dyn Error>> {
+        let word_regex = Regex::new(WORD_REGEX)?;
+
+        for chp in self.chapters.values_mut() {
+            chp.contents
+                .retain(|content| !paths.contains(content.get_path()));
+        }
+        self.chapters.retain(|_, chp| !chp.contents.is_empty());
+
+        for path in paths.iter().filter(|path| Self::is_content_path(path)) {
+            if let Some(content) =
+                Self::read_content(path.clone(), self.collect_section_data, &word_regex)
+            {
+                self.insert_content(content);
             }
-        });
+        }
+        self.sort_chapter_contents();
 
-        // Sort each chapter's sections contents by word count, descending
-        for chp in chapters.values_mut() {
-            chp.contents.sort_by_key(|c| {
-                Reverse(match c {
-                    Content::Section { word_count, .. } => *word_count,
-                    Content::Svg { .. } => 0,
-                })
-            });
+        if paths
+            .iter()
+            .any(|path| path.file_name() == Some(OsStr::new(SUMMARY_FILE_NAME)))
+        {
+            self.summary_chp_nums = Self::parse_summary_chp_nums(&self.src_dir)?;
+        }
+
+        Ok(())
+    }
+
+    /// Set words per page for page count estimates, for the book and all its chapters
+    pub fn set_words_per_page(&mut self, words_per_page: NonZeroUsize) {
+        self.words_per_page = words_per_page.get();
+        for chp in self.chapters.values_mut() {
+            chp.words_per_page = words_per_page.get();
+        }
+    }
+
+    /// Estimated page count, per the configured words per page
+    pub fn get_page_count(&self) -> usize {
+        self.get_word_count() / self.words_per_page
+    }
+
+    /// Check chapter numbers are contiguous, from 1 up to the last chapter (frontmatter and appendix excluded).
+    /// Returns any missing chapter numbers.
+    pub fn validate_chapter_numbering(&self) -> Result<(), Vec<usize>> {
+        let last_chp_num = self
+            .chapters
+            .keys()
+            .copied()
+            .filter(|num| *num != NON_CHP_NUM && *num != APPENDIX_CHP_NUM)
+            .max()
+            .unwrap_or(NON_CHP_NUM);
+
+        let missing: Vec<usize> = (1..=last_chp_num)
+            .filter(|num| !self.chapters.contains_key(num))
+            .collect();
+
+        match missing.is_empty() {
+            true => Ok(()),
+            false => Err(missing),
+        }
+    }
+
+    /// Get chapters in the order the rendered book presents them, per links in `SUMMARY.md` (e.g. frontmatter first).
+    /// Chapters that `SUMMARY.md` doesn't link, or all chapters if it's missing, follow in numeric order.
+    pub fn chapters_in_summary_order(&self) -> Vec<&Chapter> {
+        let mut ordered: Vec<&Chapter> = Vec::with_capacity(self.chapters.len());
+
+        for num in self.summary_chp_nums.iter().chain(self.chapters.keys()) {
+            if let Some(chp) = self.chapters.get(num) {
+                if !ordered.iter().any(|c| c.number == *num) {
+                    ordered.push(chp);
+                }
+            }
         }
 
-        Ok(Book { chapters })
+        ordered
     }
 
     /// Get a linter for frontmatter that doesn't belong to any chapter
@@ -85,6 +174,7 @@ impl Book {
         let mut linter = LinterBuilder::new()
             .add_rule(Level::Fatal, Rule(&rule_md_extension))
             .add_rule(Level::Fatal, Rule(&rule_no_draft_path))
+            .add_rule(Level::Fatal, Rule(&rule_no_absolute_paths))
             .add_rule(Level::Fatal, Rule(&rule_nonempty));
 
         for (num, chp) in self.chapters.iter() {
@@ -105,10 +195,15 @@ impl Book {
         let mut linter = LinterBuilder::new()
             .add_rule(Level::Fatal, Rule(&rule_md_extension))
             .add_rule(Level::Fatal, Rule(&rule_no_draft_path))
+            .add_rule(Level::Fatal, Rule(&rule_no_absolute_paths))
             .add_rule(Level::Fatal, Rule(&rule_nonempty))
             .add_rule(Level::Fatal, Rule(&rule_header_and_footer))
             .add_rule(Level::Fatal, Rule(&rule_heading_sizes))
+            .add_rule(Level::Fatal, Rule(&rule_unique_learning_outcomes))
             .add_rule(Level::Fatal, Rule(&rule_meta_tags))
+            .add_rule(Level::Fatal, Rule(&rule_unique_footnotes))
+            .add_rule(Level::Fatal, Rule(&rule_code_fence_balanced))
+            .add_rule(Level::Warning, Rule(&rule_code_fence_language))
             .add_rule(Level::Warning, Rule(&rule_has_svg));
 
         for (num, chp) in self.chapters.iter() {
@@ -133,9 +228,13 @@ impl Book {
         let mut linter = LinterBuilder::new()
             .add_rule(Level::Fatal, Rule(&rule_md_extension))
             .add_rule(Level::Fatal, Rule(&rule_no_draft_path))
+            .add_rule(Level::Fatal, Rule(&rule_no_absolute_paths))
             .add_rule(Level::Fatal, Rule(&rule_nonempty))
             .add_rule(Level::Fatal, Rule(&rule_footer))
-            .add_rule(Level::Fatal, Rule(&rule_heading_sizes));
+            .add_rule(Level::Fatal, Rule(&rule_heading_sizes))
+            .add_rule(Level::Fatal, Rule(&rule_unique_footnotes))
+            .add_rule(Level::Fatal, Rule(&rule_code_fence_balanced))
+            .add_rule(Level::Warning, Rule(&rule_code_fence_language));
 
         for (num, chp) in self.chapters.iter() {
             if *num != NON_CHP_NUM {
@@ -176,65 +275,242 @@ impl Book {
         linter.build()
     }
 
-    // Collection book contents
-    // Adapted from: https://da-data.blogspot.com/2020/10/no-c-still-isnt-cutting-it.html
-    fn collect_contents(collect_section_data: bool, word_regex: &Regex) -> Vec<Content> {
-        WalkDir::new(BOOK_SRC_DIR_RELATIVE)
-            .into_iter()
-            .filter_map(Result::ok)
-            // Markdown and SVG extension names
-            .filter(|dir_ent| {
-                matches!(
-                    dir_ent.path().extension().and_then(OsStr::to_str),
-                    Some("md") | Some("MD") | Some("svg") | Some("SVG")
-                )
-            })
-            // Openable
-            .map(|dir_entry| (dir_entry.clone(), File::open(dir_entry.path())))
-            .filter_map(|(dir_entry, file)| match file {
-                Ok(file) => Some((dir_entry, file)),
+    /// Get a linter for chapter diagrams (size and dimensions)
+    pub fn get_diagram_linter(&self) -> Linter<'_> {
+        let mut linter = LinterBuilder::new()
+            .add_rule(Level::Fatal, Rule(&rule_svg_size_limit))
+            .add_rule(Level::Warning, Rule(&rule_svg_aspect_ratio));
+
+        for (num, chp) in self.chapters.iter() {
+            if *num != NON_CHP_NUM {
+                for content in chp.contents.iter() {
+                    if matches!(content, Content::Svg { .. }) {
+                        linter = linter.add_content(content);
+                    }
+                }
+            }
+        }
+
+        linter.build()
+    }
+
+    /// Get data hashes for all chapter contents, by path (see [`Book::lint_changed`])
+    pub fn get_content_hashes(&self) -> BTreeMap<PathBuf, u64> {
+        self.chapters
+            .values()
+            .flat_map(|chp| chp.contents.iter())
+            .map(|content| (content.get_path().clone(), content.get_hash()))
+            .collect()
+    }
+
+    /// Run all linters, skipping contents unchanged since a previous run (same hash for same path).
+    /// Returns current hashes on success, to pass into the next run.
+    ///
+    /// Rules apply to individual files, so unchanged files can't start failing.
+    /// Unless the linter itself changes - discard previous hashes after updating rules.
+    pub fn lint_changed(
+        &self,
+        previous_hashes: &BTreeMap<PathBuf, u64>,
+        log_warn: bool,
+    ) -> Result<BTreeMap<PathBuf, u64>, LeveledLintError<'_>> {
+        for linter in [
+            self.get_non_chp_linter(),
+            self.get_chp_intro_linter(),
+            self.get_chp_sections_linter(),
+            self.get_svg_linter(),
+            self.get_diagram_linter(),
+        ] {
+            linter.skip_unchanged(previous_hashes).run(log_warn)?;
+        }
+
+        Ok(self.get_content_hashes())
+    }
+
+    /// Find SVGs not embedded (via `<img src>`) by any section, sorted by path.
+    /// Requires section data (see [`Book::try_new`]), otherwise every SVG is reported.
+    pub fn find_orphan_svgs(&self) -> Vec<PathBuf> {
+        let img_selector = scraper::Selector::parse("img").unwrap();
+        let contents = || self.chapters.values().flat_map(|chp| chp.contents.iter());
+
+        let referenced: BTreeSet<PathBuf> = contents()
+            .filter_map(|content| match content {
+                Content::Section {
+                    path,
+                    lines: Some(lines),
+                    ..
+                } => Some((path, lines)),
                 _ => None,
             })
-            // Actual files
-            .filter(|(_, file)| {
-                file.metadata()
-                    .map(|meta_data| meta_data.is_file())
-                    .unwrap_or(false)
+            .flat_map(|(path, lines)| {
+                let html = scraper::Html::parse_fragment(&lines.join("\n"));
+                let page_dir = self.get_site_path(path.parent().unwrap_or(Path::new("")));
+                html.select(&img_selector)
+                    .filter_map(|img| img.value().attr("src"))
+                    // Skip external images
+                    .filter(|src| !src.contains("://"))
+                    .map(|src| Self::normalize_site_path(&page_dir.join(src)))
+                    .collect::<Vec<_>>()
             })
-            .par_bridge()
-            // Construct content data model
-            .map(|(dir_entry, file)| {
-                let path = dir_entry.path().to_path_buf();
-                let reader = BufReader::new(file);
-                let lines = reader
-                    .lines()
-                    .map_while(Result::ok)
-                    .collect::<Vec<String>>();
-
-                match path.extension().and_then(OsStr::to_str) {
-                    Some("svg") | Some("SVG") => Content::Svg {
-                        path,
-                        lines: if collect_section_data {
-                            Some(lines)
-                        } else {
-                            None
-                        },
-                    },
-                    Some("md") | Some("MD") => Content::Section {
-                        path,
-                        word_count: Self::count_words(&lines, word_regex),
-                        lines: if collect_section_data {
-                            Some(lines)
-                        } else {
-                            None
+            .collect();
+
+        let mut orphans: Vec<PathBuf> = contents()
+            .filter(|content| matches!(content, Content::Svg { .. }))
+            .map(|content| content.get_path())
+            .filter(|path| !referenced.contains(&self.get_site_path(path)))
+            .cloned()
+            .collect();
+
+        orphans.sort();
+        orphans
+    }
+
+    // Chapter numbers linked from `SUMMARY.md`, in link order (may repeat). Empty if there's no `SUMMARY.md`.
+    fn parse_summary_chp_nums(src_dir: &Path) -> Result<Vec<usize>, Box<dyn Error>> {
+        let summary = match fs::read_to_string(src_dir.join(SUMMARY_FILE_NAME)) {
+            Ok(summary) => summary,
+            Err(_) => return Ok(Vec::new()),
+        };
+        let link_regex = Regex::new(r"\]\(([^)]+)\)")?;
+
+        Ok(link_regex
+            .captures_iter(&summary)
+            .map(|cap| cap[1].trim().to_string())
+            // Skip external links
+            .filter(|target| !target.contains("://"))
+            .filter_map(|target| (&Self::normalize_site_path(Path::new(&target))).get_chp())
+            .collect())
+    }
+
+    // Path relative to the book source root, i.e. the rendered site's root
+    fn get_site_path(&self, path: &Path) -> PathBuf {
+        Self::normalize_site_path(path.strip_prefix(&self.src_dir).unwrap_or(path))
+    }
+
+    // Resolve `.`, `..`, and root components like a browser resolves a URL path: `..` can't go above the site root
+    fn normalize_site_path(path: &Path) -> PathBuf {
+        let mut normalized = PathBuf::new();
+
+        for component in path.components() {
+            match component {
+                Component::Normal(name) => normalized.push(name),
+                Component::ParentDir => {
+                    normalized.pop();
+                }
+                Component::CurDir | Component::RootDir | Component::Prefix(_) => continue,
+            }
+        }
+
+        normalized
+    }
+
+    // Add content to its chapter, creating the chapter if needed. Non-chapter content is skipped.
+    fn insert_content(&mut self, content: Content) {
+        if let Some(number) = content.get_chp() {
+            match self.chapters.get_mut(&number) {
+                Some(chp) => chp.contents.push(content),
+                None => {
+                    self.chapters.insert(
+                        number,
+                        Chapter {
+                            contents: vec![content],
+                            number,
+                            words_per_page: self.words_per_page,
                         },
-                    },
-                    _ => unreachable!("File extensions pre-filtered"),
+                    );
                 }
+            }
+        }
+    }
+
+    // Sort each chapter's sections contents by word count, descending
+    fn sort_chapter_contents(&mut self) {
+        for chp in self.chapters.values_mut() {
+            chp.contents.sort_by_key(|c| {
+                Reverse(match c {
+                    Content::Section { word_count, .. } => *word_count,
+                    Content::Svg { .. } | Content::Source { .. } => 0,
+                })
+            });
+        }
+    }
+
+    // Markdown and SVG extension names
+    pub(crate) fn is_content_path(path: &Path) -> bool {
+        matches!(
+            path.extension().and_then(OsStr::to_str),
+            Some("md") | Some("MD") | Some("svg") | Some("SVG")
+        )
+    }
+
+    // Collection book contents
+    // Adapted from: https://da-data.blogspot.com/2020/10/no-c-still-isnt-cutting-it.html
+    fn collect_contents(
+        src_dir: &Path,
+        collect_section_data: bool,
+        word_regex: &Regex,
+    ) -> Vec<Content> {
+        WalkDir::new(src_dir)
+            .into_iter()
+            .filter_map(Result::ok)
+            .filter(|dir_ent| Self::is_content_path(dir_ent.path()))
+            .par_bridge()
+            .filter_map(|dir_entry| {
+                Self::read_content(
+                    dir_entry.path().to_path_buf(),
+                    collect_section_data,
+                    word_regex,
+                )
             })
             .collect()
     }
 
+    // Construct content data model for a Markdown or SVG file. `None` if it isn't an openable, actual file.
+    fn read_content(
+        path: PathBuf,
+        collect_section_data: bool,
+        word_regex: &Regex,
+    ) -> Option<Content> {
+        let file = File::open(&path).ok()?;
+        if !file
+            .metadata()
+            .map(|meta_data| meta_data.is_file())
+            .unwrap_or(false)
+        {
+            return None;
+        }
+
+        let reader = BufReader::new(file);
+        let lines = reader
+            .lines()
+            .map_while(Result::ok)
+            .collect::<Vec<String>>();
+
+        let hash = Content::hash_lines(&lines);
+
+        match path.extension().and_then(OsStr::to_str) {
+            Some("svg") | Some("SVG") => Some(Content::Svg {
+                path,
+                hash,
+                lines: if collect_section_data {
+                    Some(lines)
+                } else {
+                    None
+                },
+            }),
+            Some("md") | Some("MD") => Some(Content::Section {
+                path,
+                word_count: Self::count_words(&lines, word_regex),
+                hash,
+                lines: if collect_section_data {
+                    Some(lines)
+                } else {
+                    None
+                },
+            }),
+            _ => unreachable!("File extensions pre-filtered"),
+        }
+    }
+
     // Count words in a given file
     fn count_words(lines: &[String], word_regex: &Regex) -> usize {
         lines
@@ -259,10 +535,212 @@ impl fmt::Display for Book {
             "{}: {} words ({} pages), {} diagrams",
             "BOOK TOTAL".yellow(),
             word_count.separated_string().bright_green(),
-            (word_count / WORDS_PER_PAGE)
-                .separated_string()
-                .bright_cyan(),
+            self.get_page_count().separated_string().bright_cyan(),
             self.get_diagram_count().separated_string().bright_blue(),
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Book;
+    use crate::test_utils::TempFixture;
+    use crate::traits::GetMetrics;
+    use crate::{LeveledLintError, WORDS_PER_PAGE_DEFAULT};
+    use std::{collections::BTreeMap, fs, num::NonZeroUsize};
+
+    #[test]
+    fn test_try_new_in() {
+        let fixture = TempFixture::with_files(
+            "book",
+            [
+                ("chp1/_index.md", "# Intro\n\nOne two three."),
+                ("chp2/section.md", "# Section\n\nFour five."),
+                ("chp2/notes.txt", "Not book content."),
+            ],
+        );
+
+        let book = Book::try_new_in(fixture.path(), true).unwrap();
+
+        assert_eq!(book.chapters.keys().copied().collect::<Vec<_>>(), [1, 2]);
+        assert_eq!(book.chapters[&1].contents.len(), 1);
+        assert_eq!(book.chapters[&2].contents.len(), 1);
+        assert_eq!(book.get_word_count(), 7);
+    }
+
+    #[test]
+    fn test_words_per_page() {
+        let fixture = TempFixture::with_files(
+            "wpp",
+            [
+                ("chp1/_index.md", "word ".repeat(750).as_str()),
+                ("chp2/_index.md", "word ".repeat(300).as_str()),
+            ],
+        );
+
+        let mut book = Book::try_new_in(fixture.path(), true).unwrap();
+
+        assert_eq!(WORDS_PER_PAGE_DEFAULT, 500);
+        assert_eq!(book.get_page_count(), 2);
+        assert_eq!(book.chapters[&1].get_page_count(), 1);
+        assert_eq!(book.chapters[&2].get_page_count(), 0);
+
+        book.set_words_per_page(NonZeroUsize::new(250).unwrap());
+        assert_eq!(book.get_page_count(), 4);
+        assert_eq!(book.chapters[&1].get_page_count(), 3);
+        assert_eq!(book.chapters[&2].get_page_count(), 1);
+    }
+
+    #[test]
+    fn test_validate_chapter_numbering() {
+        let fixture = TempFixture::with_files(
+            "numbering",
+            [
+                ("chp1/_index.md", "# Chapter"),
+                ("chp2/_index.md", "# Chapter"),
+                ("chp4/_index.md", "# Chapter"),
+                ("chp16_appendix/_index.md", "# Chapter"),
+                ("landing.md", "# Landing"),
+            ],
+        );
+
+        let book = Book::try_new_in(fixture.path(), false).unwrap();
+        fixture.write("chp3/_index.md", "# Chapter");
+        let fixed_book = Book::try_new_in(fixture.path(), false).unwrap();
+
+        assert_eq!(book.validate_chapter_numbering(), Err(vec![3]));
+        assert_eq!(fixed_book.validate_chapter_numbering(), Ok(()));
+    }
+
+    #[test]
+    fn test_chapters_in_summary_order() {
+        let fixture = TempFixture::with_files(
+            "summary",
+            [
+                ("chp1/_index.md", "# Chapter"),
+                ("chp2/_index.md", "# Chapter"),
+                ("chp3/_index.md", "# Chapter"),
+                ("chp16_appendix/_index.md", "# Chapter"),
+            ],
+        );
+
+        let unsummarized_book = Book::try_new_in(fixture.path(), false).unwrap();
+        fixture.write(
+            "SUMMARY.md",
+            "# Summary\n\n\
+            [Preface](landing.md)\n\
+            [Blog](https://highassurance.rs/chp1/_index.md)\n\n\
+            * [Two](./chp2/_index.md)\n\
+            * [Appendix](./chp16_appendix/_index.md)\n\
+            * [One](./chp1/_index.md)\n\
+            \x20   * [Draft]()\n",
+        );
+        let book = Book::try_new_in(fixture.path(), false).unwrap();
+
+        let order = |book: &Book| {
+            book.chapters_in_summary_order()
+                .iter()
+                .map(|chp| chp.number)
+                .collect::<Vec<_>>()
+        };
+
+        // No `SUMMARY.md`, numeric order
+        assert_eq!(order(&unsummarized_book), [1, 2, 3, 16]);
+
+        // Non-chapter and external links skipped, unlinked chapter 3 last
+        assert_eq!(order(&book), [2, 16, 1, 3]);
+    }
+
+    #[test]
+    fn test_lint_changed() {
+        let fixture = TempFixture::with_files(
+            "incremental",
+            [(
+                "chp2/section.md",
+                "# Section\n\nSee /book-draft/src/chp2.md",
+            )],
+        );
+
+        let book = Book::try_new_in(fixture.path(), true).unwrap();
+        fixture.write(
+            "chp2/section.md",
+            "# Section\n\nSee /book-draft/src/chp3.md",
+        );
+        let edited_book = Book::try_new_in(fixture.path(), true).unwrap();
+
+        // No previous run, section linted
+        assert!(matches!(
+            book.lint_changed(&BTreeMap::new(), true),
+            Err(LeveledLintError::Fatal(_))
+        ));
+
+        // Unchanged section skipped
+        let hashes = book.get_content_hashes();
+        assert_eq!(hashes.len(), 1);
+        assert_eq!(book.lint_changed(&hashes, false), Ok(hashes.clone()));
+
+        // Changed section linted
+        assert_ne!(edited_book.get_content_hashes(), hashes);
+        assert!(matches!(
+            edited_book.lint_changed(&hashes, true),
+            Err(LeveledLintError::Fatal(_))
+        ));
+    }
+
+    #[test]
+    fn test_refresh() {
+        let fixture = TempFixture::with_files(
+            "refresh",
+            [
+                ("chp1/_index.md", "One two."),
+                ("chp2/_index.md", "Three."),
+                ("chp3/_index.md", "Four."),
+            ],
+        );
+
+        let mut book = Book::try_new_in(fixture.path(), true).unwrap();
+
+        // Edit, add, remove, and a non-content file
+        let edited = fixture.write("chp1/_index.md", "One two three four.");
+        let added = fixture.write("chp4/_index.md", "Five.");
+        let ignored = fixture.write("chp4/notes.txt", "Not book content.");
+        let removed = fixture.path().join("chp2/_index.md");
+        fs::remove_file(&removed).unwrap();
+
+        // Unlisted changes aren't picked up
+        fixture.write("chp3/_index.md", "Four, edited.");
+
+        book.refresh(&[edited, added, ignored, removed]).unwrap();
+
+        assert_eq!(book.chapters.keys().copied().collect::<Vec<_>>(), [1, 3, 4]);
+        assert_eq!(book.get_word_count(), 4 + 1 + 1);
+    }
+
+    #[test]
+    fn test_find_orphan_svgs() {
+        let fixture = TempFixture::with_files(
+            "orphans",
+            [
+                (
+                    "chp1/_index.md",
+                    "# Intro\n\n<p align=\"center\">\n  <img width=\"80%\" src=\"used.svg\">\n</p>",
+                ),
+                (
+                    "landing.md",
+                    "<p align=\"center\"><img src=\"../img/logo.svg\" width=\"65%\"></p>",
+                ),
+                ("chp1/used.svg", "<svg></svg>"),
+                ("chp1/orphan.svg", "<svg></svg>"),
+                ("img/logo.svg", "<svg></svg>"),
+            ],
+        );
+
+        let book = Book::try_new_in(fixture.path(), true).unwrap();
+
+        // Site-root-relative `../img/logo.svg` resolves to `img/logo.svg`
+        assert_eq!(
+            book.find_orphan_svgs(),
+            vec![fixture.path().join("chp1").join("orphan.svg")]
+        );
+    }
+}
@@ -1,13 +1,14 @@
-use crate::{content::Content, traits::GetMetrics, WORDS_PER_PAGE};
+use crate::{content::Content, traits::GetMetrics};
 use colored::*;
 use separator::Separatable;
-use std::{ffi::OsStr, fmt};
+use std::{ffi::OsStr, fmt, path::PathBuf};
 
 /// Displayable chapter data model
 pub struct Chapter {
     /// Chapter contents
     pub contents: Vec<Content>,
     pub(crate) number: usize,
+    pub(crate) words_per_page: usize,
 }
 
 impl GetMetrics for Chapter {
@@ -16,7 +17,7 @@ impl GetMetrics for Chapter {
             .iter()
             .map(|c| match c {
                 Content::Section { word_count, .. } => word_count,
-                Content::Svg { .. } => &0,
+                Content::Svg { .. } | Content::Source { .. } => &0,
             })
             .sum()
     }
@@ -25,11 +26,30 @@ impl GetMetrics for Chapter {
         self.contents
             .iter()
             .map(|c| match c {
-                Content::Section { .. } => 0,
+                Content::Section { .. } | Content::Source { .. } => 0,
                 Content::Svg { .. } => 1,
             })
             .sum()
     }
+
+    fn get_section_breakdown(&self) -> Vec<(PathBuf, usize)> {
+        self.contents
+            .iter()
+            .filter_map(|c| match c {
+                Content::Section {
+                    path, word_count, ..
+                } => Some((path.clone(), *word_count)),
+                Content::Svg { .. } | Content::Source { .. } => None,
+            })
+            .collect()
+    }
+}
+
+impl Chapter {
+    /// Estimated page count, per the configured words per page
+    pub fn get_page_count(&self) -> usize {
+        self.get_word_count() / self.words_per_page
+    }
 }
 
 impl fmt::Display for Chapter {
@@ -47,30 +67,67 @@ impl fmt::Display for Chapter {
                 _ => (self.number.to_string() + ":").yellow(),
             },
             word_count.separated_string().bright_green(),
-            (word_count / WORDS_PER_PAGE)
-                .separated_string()
-                .bright_cyan(),
+            self.get_page_count().separated_string().bright_cyan(),
             self.get_diagram_count().separated_string().bright_blue()
         )?;
 
-        for content in self.contents.iter() {
-            match content {
-                Content::Section {
-                    path, word_count, ..
-                } => {
-                    if let Some(file_name) = path.as_path().file_name().and_then(OsStr::to_str) {
-                        writeln!(
-                            f,
-                            " - {}: {}",
-                            file_name.bright_magenta(),
-                            word_count.separated_string().bright_green()
-                        )?;
-                    }
-                }
-                Content::Svg { .. } => continue,
+        for (path, word_count) in self.get_section_breakdown() {
+            if let Some(file_name) = path.as_path().file_name().and_then(OsStr::to_str) {
+                writeln!(
+                    f,
+                    " - {}: {}",
+                    file_name.bright_magenta(),
+                    word_count.separated_string().bright_green()
+                )?;
             }
         }
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Chapter;
+    use crate::{content::Content, traits::GetMetrics, WORDS_PER_PAGE_DEFAULT};
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_section_breakdown() {
+        let section = |path: &str, word_count| Content::Section {
+            path: PathBuf::from(path),
+            lines: None,
+            word_count,
+            hash: 0,
+        };
+
+        let chp = Chapter {
+            contents: vec![
+                section("chp3/_index.md", 120),
+                Content::Svg {
+                    path: PathBuf::from("chp3/diagram.svg"),
+                    lines: None,
+                    hash: 0,
+                },
+                section("chp3/rust_basics.md", 900),
+                section("chp3/rust_ownership.md", 450),
+            ],
+            number: 3,
+            words_per_page: WORDS_PER_PAGE_DEFAULT,
+        };
+
+        let breakdown = chp.get_section_breakdown();
+        assert_eq!(
+            breakdown,
+            [
+                (PathBuf::from("chp3/_index.md"), 120),
+                (PathBuf::from("chp3/rust_basics.md"), 900),
+                (PathBuf::from("chp3/rust_ownership.md"), 450),
+            ]
+        );
+        assert_eq!(
+            breakdown.iter().map(|(_, cnt)| cnt).sum::<usize>(),
+            chp.get_word_count()
+        );
+    }
+}
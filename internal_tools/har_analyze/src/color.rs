@@ -0,0 +1,68 @@
+use std::{ffi::OsString, io::IsTerminal};
+
+use colored::Colorize;
+
+const NO_COLOR_ENV_VAR: &str = "NO_COLOR";
+
+/// Disable colorized output if requested via flag or `NO_COLOR`, or if stdout isn't a terminal.
+/// Returns `true` if color remains enabled.
+pub fn set_color_mode(no_color_flag: bool) -> bool {
+    let enabled = color_enabled(
+        no_color_flag,
+        std::env::var_os(NO_COLOR_ENV_VAR),
+        std::io::stdout().is_terminal(),
+    );
+
+    if !enabled {
+        colored::control::set_override(false);
+    }
+
+    enabled
+}
+
+// Per <https://no-color.org>, `NO_COLOR` only applies if set to a non-empty value.
+fn color_enabled(no_color_flag: bool, no_color_env: Option<OsString>, is_tty: bool) -> bool {
+    let no_color_env = no_color_env.is_some_and(|val| !val.is_empty());
+    !no_color_flag && !no_color_env && is_tty
+}
+
+/// Status message for a passing step, e.g. "Lint OK".
+pub fn status_ok(step: &str) -> String {
+    format!("{} {}", step, "OK".green())
+}
+
+/// Non-fatal warning message, e.g. "WARNING: Missing chapters [5, 6]".
+pub fn status_warn(msg: &str) -> String {
+    format!("{}: {}", "WARNING".yellow(), msg)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{color_enabled, status_ok};
+    use colored::Colorize;
+    use std::ffi::OsString;
+
+    #[test]
+    fn test_color_enabled() {
+        assert!(color_enabled(false, None, true));
+        assert!(color_enabled(false, Some(OsString::new()), true));
+        assert!(!color_enabled(true, None, true));
+        assert!(!color_enabled(false, Some(OsString::from("1")), true));
+        assert!(!color_enabled(false, None, false));
+    }
+
+    #[test]
+    fn test_no_ansi_when_disabled() {
+        colored::control::set_override(false);
+
+        for s in [
+            status_ok("Lint"),
+            format!("{}: {:?}", "WARNING".yellow(), "msg"),
+            "BOOK TOTAL".yellow().to_string(),
+        ] {
+            assert!(!s.contains('\x1b'), "ANSI escape in {:?}", s);
+        }
+
+        colored::control::unset_override();
+    }
+}
@@ -13,6 +13,8 @@ pub enum Content {
         lines: Option<Vec<String>>,
         /// Section word count
         word_count: usize,
+        /// Section data hash, see [`Content::hash_lines`]
+        hash: u64,
     },
     /// An individual diagram
     Svg {
@@ -20,6 +22,17 @@ pub enum Content {
         path: PathBuf,
         /// SVG data (optionally collected, line-oriented)
         lines: Option<Vec<String>>,
+        /// SVG data hash, see [`Content::hash_lines`]
+        hash: u64,
+    },
+    /// An individual code snippet Rust source file
+    Source {
+        /// Source path
+        path: PathBuf,
+        /// Source data (line-oriented)
+        lines: Option<Vec<String>>,
+        /// Source data hash, see [`Content::hash_lines`]
+        hash: u64,
     },
 }
 
@@ -29,8 +42,32 @@ impl Content {
         match self {
             Self::Section { path, .. } => path,
             Self::Svg { path, .. } => path,
+            Self::Source { path, .. } => path,
+        }
+    }
+
+    /// Get data hash for this content, e.g. a cache key for incremental linting
+    pub fn get_hash(&self) -> u64 {
+        match self {
+            Self::Section { hash, .. } => *hash,
+            Self::Svg { hash, .. } => *hash,
+            Self::Source { hash, .. } => *hash,
         }
     }
+
+    /// Hash line-oriented data (64-bit FNV-1a).
+    /// Unlike `std`'s `DefaultHasher`, output is stable across toolchains, so hashes can be persisted between runs.
+    pub fn hash_lines(lines: &[String]) -> u64 {
+        const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+        lines
+            .iter()
+            .flat_map(|line| line.bytes().chain(std::iter::once(b'\n')))
+            .fold(FNV_OFFSET_BASIS, |hash, byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+            })
+    }
 }
 
 impl GetChapter for Content {
@@ -38,6 +75,7 @@ impl GetChapter for Content {
         match self {
             Self::Section { path, .. } => path.get_chp(),
             Self::Svg { path, .. } => path.get_chp(),
+            Self::Source { path, .. } => path.get_chp(),
         }
     }
 }
@@ -1,6 +1,6 @@
 use crate::{rules::Rule, Content};
 use colored::*;
-use std::{fmt, num::NonZeroUsize, path::PathBuf};
+use std::{collections::BTreeMap, fmt, num::NonZeroUsize, path::PathBuf};
 
 #[derive(Debug)]
 #[cfg_attr(test, derive(PartialEq))]
@@ -76,11 +76,19 @@ impl<'a> Linter<'a> {
         LinterBuilder::default()
     }
 
-    pub fn run(&self, log_warn: bool) -> Result<(), LeveledLintError> {
+    /// Drop contents whose hash matches a previous run's, for incremental linting
+    pub fn skip_unchanged(mut self, previous_hashes: &BTreeMap<PathBuf, u64>) -> Linter<'a> {
+        self.contents
+            .retain(|content| previous_hashes.get(content.get_path()) != Some(&content.get_hash()));
+        self
+    }
+
+    pub fn run(&self, log_warn: bool) -> Result<(), LeveledLintError<'a>> {
         for content in &self.contents {
             let (path, lines) = match content {
                 Content::Section { path, lines, .. } => (path, lines),
-                Content::Svg { path, lines } => (path, lines),
+                Content::Svg { path, lines, .. } => (path, lines),
+                Content::Source { path, lines, .. } => (path, lines),
             };
 
             match lines {
@@ -167,11 +175,13 @@ mod tests {
             path: PathBuf::from("/test/path/to/file.md"),
             word_count: 0,
             lines: None,
+            hash: 0,
         };
 
         let default_svg = Content::Svg {
             path: PathBuf::default(),
             lines: None,
+            hash: 0,
         };
 
         let linter = Linter {
@@ -2,9 +2,12 @@ use clap::{
     builder::{styling::AnsiColor, Styles},
     Parser,
 };
-use color_eyre::eyre::Result;
-use colored::*;
+use color_eyre::{
+    config::{HookBuilder, Theme},
+    eyre::Result,
+};
 use lazy_static::lazy_static;
+use std::{num::NonZeroUsize, path::PathBuf};
 
 lazy_static! {
     static ref CMD_COLOR: Styles = Styles::styled()
@@ -38,16 +41,41 @@ struct Args {
     #[arg(long, requires = "lint")]
     log_warn: bool,
 
+    /// After linting, keep watching the book source and re-lint changed files until interrupted.
+    #[cfg(feature = "watch")]
+    #[arg(long, requires = "lint", conflicts_with = "update")]
+    watch: bool,
+
     /// Update page/diagram count badges and missing meta tags.
     #[arg(short, long)]
     update: bool,
+
+    /// Book source directory to analyze. Defaults to this repo's book.
+    #[arg(long, value_name = "DIR", conflicts_with = "update")]
+    src: Option<PathBuf>,
+
+    /// Words per page, for page count estimates in metrics and badges.
+    #[arg(long, value_name = "N", default_value_t = NonZeroUsize::new(har_analyze::WORDS_PER_PAGE_DEFAULT).unwrap())]
+    words_per_page: NonZeroUsize,
+
+    /// Disable colorized output. Also disabled if `NO_COLOR` is set or stdout isn't a terminal.
+    #[arg(long)]
+    no_color: bool,
 }
 
 fn main() -> Result<()> {
-    color_eyre::install()?;
-
     let args = Args::parse();
-    let book = har_analyze::Book::try_new(args.lint).unwrap();
+
+    match har_analyze::set_color_mode(args.no_color) {
+        true => color_eyre::install()?,
+        false => HookBuilder::default().theme(Theme::new()).install()?,
+    }
+
+    let mut book = match &args.src {
+        Some(src_dir) => har_analyze::Book::try_new_in(src_dir, args.lint).unwrap(),
+        None => har_analyze::Book::try_new(args.lint).unwrap(),
+    };
+    book.set_words_per_page(args.words_per_page);
 
     // Status Report
     if args.metrics {
@@ -58,7 +86,13 @@ fn main() -> Result<()> {
     if args.update {
         har_analyze::update_badges(&book).unwrap();
         har_analyze::update_meta_tags(&book).unwrap();
-        println!("Updates {}", "OK".green());
+        println!("{}", har_analyze::status_ok("Updates"));
+    }
+
+    // Verify, continuously (first pass lints everything, failures don't exit)
+    #[cfg(feature = "watch")]
+    if args.watch {
+        har_analyze::watch_lint(args.src.as_deref(), args.log_warn).unwrap();
     }
 
     // Verify
@@ -67,7 +101,34 @@ fn main() -> Result<()> {
         book.get_chp_intro_linter().run(args.log_warn).unwrap();
         book.get_chp_sections_linter().run(args.log_warn).unwrap();
         book.get_svg_linter().run(args.log_warn).unwrap();
-        println!("Lint {}", "OK".green());
+        book.get_diagram_linter().run(args.log_warn).unwrap();
+
+        // Code snippets live alongside this repo's book, not a custom source directory
+        if args.src.is_none() {
+            har_analyze::Snippets::try_new()
+                .unwrap()
+                .get_crate_root_linter()
+                .run(args.log_warn)
+                .unwrap();
+        }
+
+        // Unpublished chapters leave expected gaps, so never fatal
+        if let Err(missing) = book.validate_chapter_numbering() {
+            println!(
+                "{}",
+                har_analyze::status_warn(&format!("Missing chapters {:?}", missing))
+            );
+        }
+
+        // Diagrams may be staged before a section embeds them, so never fatal
+        for orphan in book.find_orphan_svgs() {
+            println!(
+                "{}",
+                har_analyze::status_warn(&format!("Orphaned SVG {:?}", orphan))
+            );
+        }
+
+        println!("{}", har_analyze::status_ok("Lint"));
     }
 
     Ok(())
@@ -8,6 +8,9 @@ pub use book::*;
 mod chapter;
 pub use chapter::*;
 
+mod color;
+pub use color::*;
+
 mod content;
 pub use content::*;
 
@@ -18,9 +21,22 @@ pub use lint::*;
 mod update;
 pub use update::*;
 
+#[cfg(feature = "watch")]
+mod watch;
+#[cfg(feature = "watch")]
+pub use watch::*;
+
 mod rules;
 
+mod snippets;
+pub use snippets::*;
+
 mod traits;
 
+#[cfg(test)]
+mod test_utils;
+
 pub(crate) const BOOK_SRC_DIR_RELATIVE: &str = "../../src";
-pub(crate) const WORDS_PER_PAGE: usize = 500;
+pub(crate) const SNIPPETS_DIR_RELATIVE: &str = "../../code_snippets";
+/// Default words per page, for page count estimates
+pub const WORDS_PER_PAGE_DEFAULT: usize = 500;
@@ -1,7 +1,8 @@
 //! Rules the linter can apply to section data.
 
 use crate::LintError;
-use std::{fmt, path::PathBuf};
+use regex::Regex;
+use std::{collections::HashSet, ffi::OsStr, fmt, path::PathBuf};
 
 /// The signature for a rule, addable to linter builder
 #[allow(clippy::type_complexity)]
@@ -42,6 +43,35 @@ pub fn rule_no_draft_path<'a>(path: &'a PathBuf, lines: &[String]) -> Result<(),
     Ok(())
 }
 
+/// Section doesn't contain a machine-specific absolute path (Unix home directory or Windows drive letter), outside of code blocks
+pub fn rule_no_absolute_paths<'a>(
+    path: &'a PathBuf,
+    lines: &[String],
+) -> Result<(), LintError<'a>> {
+    // Path must start a token, so URLs like `https://example.com/home/about/` aren't matched
+    let abs_path_regex =
+        Regex::new(r#"(?:^|[\s"'`(\[<=])(?:/home/|/Users/)[^/\s]+/|\b[A-Za-z]:\\"#).unwrap();
+    let mut in_code_block = false;
+
+    for (idx, line) in lines.iter().enumerate() {
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+
+        if !in_code_block && abs_path_regex.is_match(line) {
+            return Err(LintError::Failed {
+                path,
+                line_number: idx.into(),
+                line: line.clone(),
+                reason: "Contains absolute filesystem path".to_string(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
 /// Section contains 1+ SVGs
 pub fn rule_has_svg<'a>(path: &'a PathBuf, lines: &[String]) -> Result<(), LintError<'a>> {
     let p_start_idxs: Vec<usize> = lines
@@ -329,6 +359,159 @@ pub fn rule_heading_sizes<'a>(path: &'a PathBuf, lines: &[String]) -> Result<(),
     Ok(())
 }
 
+/// Chapter intro's "## Learning Outcomes" bullets are unique
+pub fn rule_unique_learning_outcomes<'a>(
+    path: &'a PathBuf,
+    lines: &[String],
+) -> Result<(), LintError<'a>> {
+    if path.file_name() != Some(std::ffi::OsStr::new("_index.md")) {
+        return Ok(());
+    }
+
+    let Some(outcomes_start) = lines.iter().rposition(|l| l.eq("## Learning Outcomes")) else {
+        return Ok(()); // Reported by `rule_heading_sizes`
+    };
+
+    let mut outcomes = HashSet::new();
+    for (idx, line) in lines.iter().enumerate().skip(outcomes_start + 1) {
+        if line.starts_with('#') {
+            break;
+        }
+
+        if let Some(outcome) = line.strip_prefix("* ") {
+            if !outcomes.insert(outcome.trim()) {
+                return Err(LintError::Failed {
+                    path,
+                    line_number: idx.into(),
+                    line: line.clone(),
+                    reason: "Duplicate learning outcome".to_string(),
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Section footnote labels are defined exactly once, and every reference has a definition
+pub fn rule_unique_footnotes<'a>(path: &'a PathBuf, lines: &[String]) -> Result<(), LintError<'a>> {
+    let ref_regex = Regex::new(r"\[\^([^\]]+)\]").unwrap();
+    let mut defined = HashSet::new();
+    let mut referenced = Vec::new();
+    let mut in_code_block = false;
+
+    for (idx, line) in lines.iter().enumerate() {
+        // Code blocks may contain regex character classes, e.g. `[^a-z]`
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+
+        if in_code_block {
+            continue;
+        }
+
+        // Definitions, e.g. `[^label]: ...`
+        let mut ref_start = 0;
+        if let Some((label, _)) = line.strip_prefix("[^").and_then(|l| l.split_once("]:")) {
+            if !defined.insert(label) {
+                return Err(LintError::Failed {
+                    path,
+                    line_number: idx.into(),
+                    line: line.clone(),
+                    reason: format!("Duplicate footnote label \"{}\"", label),
+                });
+            }
+            ref_start = label.len() + 4;
+        }
+
+        // References, e.g. `text[^label]`
+        for cap in ref_regex.captures_iter(&line[ref_start..]) {
+            if let Some(label) = cap.get(1) {
+                referenced.push((idx, label.as_str()));
+            }
+        }
+    }
+
+    match referenced
+        .into_iter()
+        .find(|(_, label)| !defined.contains(label))
+    {
+        Some((idx, label)) => Err(LintError::Failed {
+            path,
+            line_number: idx.into(),
+            line: lines[idx].clone(),
+            reason: format!("Undefined footnote reference \"{}\"", label),
+        }),
+        None => Ok(()),
+    }
+}
+
+// Opening code fences as (line index, info string) pairs, or `Err` with the index of an unclosed fence
+fn code_fences(lines: &[String]) -> Result<Vec<(usize, &str)>, usize> {
+    let mut fences = Vec::new();
+    let mut open: Option<(usize, usize)> = None; // (line index, fence length)
+
+    for (idx, line) in lines.iter().enumerate() {
+        let trimmed = line.trim_start();
+        let fence_len = trimmed.chars().take_while(|c| *c == '`').count();
+        if fence_len < 3 {
+            continue;
+        }
+
+        let info = trimmed[fence_len..].trim();
+        match open {
+            None => {
+                fences.push((idx, info));
+                open = Some((idx, fence_len));
+            }
+            Some((_, open_len)) if (fence_len >= open_len) && info.is_empty() => open = None,
+            Some(_) => continue,
+        }
+    }
+
+    match open {
+        Some((idx, _)) => Err(idx),
+        None => Ok(fences),
+    }
+}
+
+/// Section's code fences are all closed
+pub fn rule_code_fence_balanced<'a>(
+    path: &'a PathBuf,
+    lines: &[String],
+) -> Result<(), LintError<'a>> {
+    match code_fences(lines) {
+        Err(idx) => Err(LintError::Failed {
+            path,
+            line_number: idx.into(),
+            line: lines[idx].clone(),
+            reason: "Unclosed code fence".to_string(),
+        }),
+        Ok(_) => Ok(()),
+    }
+}
+
+/// Section's code fences all have a language identifier (e.g. "```rust"), for syntax highlighting
+pub fn rule_code_fence_language<'a>(
+    path: &'a PathBuf,
+    lines: &[String],
+) -> Result<(), LintError<'a>> {
+    let Ok(fences) = code_fences(lines) else {
+        return Ok(()); // Reported by `rule_code_fence_balanced`
+    };
+
+    match fences.into_iter().find(|(_, info)| info.is_empty()) {
+        Some((idx, _)) => Err(LintError::Failed {
+            path,
+            line_number: idx.into(),
+            line: lines[idx].clone(),
+            reason: "Code fence missing language identifier".to_string(),
+        }),
+        None => Ok(()),
+    }
+}
+
 /// Section contains meta tags
 pub fn rule_meta_tags<'a>(path: &'a PathBuf, lines: &[String]) -> Result<(), LintError<'a>> {
     for tag in crate::update::META_TAGS {
@@ -415,6 +598,138 @@ pub fn rule_valid_svg<'a>(path: &'a PathBuf, lines: &[String]) -> Result<(), Lin
     Ok(())
 }
 
+/// Default maximum SVG file size, in bytes
+pub const SVG_SIZE_LIMIT_DEFAULT: usize = 256 * 1024;
+
+/// Maximum SVG aspect ratio (long side over short side)
+pub const SVG_ASPECT_RATIO_MAX: f64 = 10.0;
+
+/// SVG file doesn't exceed the default size limit
+pub fn rule_svg_size_limit<'a>(path: &'a PathBuf, lines: &[String]) -> Result<(), LintError<'a>> {
+    svg_size_limit(path, lines, SVG_SIZE_LIMIT_DEFAULT)
+}
+
+/// SVG file doesn't exceed a given size limit (size approximated from line data, assuming `\n` line endings)
+pub fn svg_size_limit<'a>(
+    path: &'a PathBuf,
+    lines: &[String],
+    limit_bytes: usize,
+) -> Result<(), LintError<'a>> {
+    let size_bytes: usize = lines.iter().map(|l| l.len() + 1).sum();
+
+    match size_bytes > limit_bytes {
+        true => Err(LintError::Failed {
+            path,
+            line_number: 0.into(),
+            line: "N/A".to_string(),
+            reason: format!(
+                "svg size {} bytes exceeds limit of {} bytes",
+                size_bytes, limit_bytes
+            ),
+        }),
+        false => Ok(()),
+    }
+}
+
+/// SVG dimensions (`viewBox`, else `width`/`height`) imply a sane aspect ratio
+pub fn rule_svg_aspect_ratio<'a>(path: &'a PathBuf, lines: &[String]) -> Result<(), LintError<'a>> {
+    // Leading number of a length, e.g. "100px" -> 100.0
+    fn parse_len(val: &str) -> Option<f64> {
+        let val = val.trim();
+        let end = val
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(val.len());
+        val[..end].parse().ok()
+    }
+
+    let data = lines.join("\n");
+    let Ok(svg) = svg::read(&data) else {
+        return Ok(()); // Reported by `rule_valid_svg`
+    };
+
+    let Some(attrs) = svg.into_iter().find_map(|event| match event {
+        svg::parser::Event::Tag(tag, _, attrs) if tag.eq_ignore_ascii_case("svg") => Some(attrs),
+        _ => None,
+    }) else {
+        return Ok(());
+    };
+
+    let dims = match attrs.get("viewBox") {
+        Some(view_box) => {
+            let vals: Vec<f64> = view_box
+                .split(|c: char| c.is_whitespace() || c == ',')
+                .filter(|v| !v.is_empty())
+                .filter_map(|v| v.parse().ok())
+                .collect();
+            match vals[..] {
+                [_, _, width, height] => Some((width, height)),
+                _ => None,
+            }
+        }
+        None => attrs
+            .get("width")
+            .and_then(|w| parse_len(w))
+            .zip(attrs.get("height").and_then(|h| parse_len(h))),
+    };
+
+    match dims {
+        Some((width, height)) if width > 0.0 && height > 0.0 => {
+            let ratio = width.max(height) / width.min(height);
+            match ratio > SVG_ASPECT_RATIO_MAX {
+                true => Err(LintError::Failed {
+                    path,
+                    line_number: 0.into(),
+                    line: "N/A".to_string(),
+                    reason: format!(
+                        "svg aspect ratio {:.1}:1 ({}x{}) exceeds {}:1",
+                        ratio, width, height, SVG_ASPECT_RATIO_MAX
+                    ),
+                }),
+                false => Ok(()),
+            }
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Code snippet crate roots exempt from [`rule_forbid_unsafe`], matched as path suffixes.
+/// Only for snippets that intentionally demonstrate `unsafe` - add an entry alongside any such snippet.
+pub const FORBID_UNSAFE_ALLOWLIST: &[&str] = &[];
+
+/// Code snippet crate root (`lib.rs` or `main.rs`) forbids unsafe code, unless allowlisted
+pub fn rule_forbid_unsafe<'a>(path: &'a PathBuf, lines: &[String]) -> Result<(), LintError<'a>> {
+    forbid_unsafe(path, lines, FORBID_UNSAFE_ALLOWLIST)
+}
+
+/// Code snippet crate root (`lib.rs` or `main.rs`) forbids unsafe code, unless its path ends with an allowlist entry
+pub fn forbid_unsafe<'a>(
+    path: &'a PathBuf,
+    lines: &[String],
+    allowlist: &[&str],
+) -> Result<(), LintError<'a>> {
+    let is_crate_root = matches!(
+        path.file_name().and_then(OsStr::to_str),
+        Some("lib.rs") | Some("main.rs")
+    );
+
+    if !is_crate_root || allowlist.iter().any(|allowed| path.ends_with(allowed)) {
+        return Ok(());
+    }
+
+    match lines
+        .iter()
+        .any(|line| line.trim() == "#![forbid(unsafe_code)]")
+    {
+        true => Ok(()),
+        false => Err(LintError::Failed {
+            path,
+            line_number: 0.into(),
+            line: "N/A".to_string(),
+            reason: "Missing `#![forbid(unsafe_code)]`".to_string(),
+        }),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::path::PathBuf;
@@ -488,4 +803,258 @@ mod tests {
         assert!(rule_md_extension(&path, &lines).is_ok());
         assert!(rule_heading_sizes(&path, &lines).is_err());
     }
+
+    #[test]
+    fn test_unique_footnotes() {
+        use super::rule_unique_footnotes;
+
+        const MD: &str = r#######"
+            # Heading 1
+            Some claim[^First], another claim[^Second].
+            Repeated citation[^First].
+            ```
+            let re = Regex::new(r"[^a-z]").unwrap();
+            ```
+            ---
+            [^First]: First source.
+            [^Second]: Second source, citing a third[^Third].
+            [^Third]: Third source.
+            "#######;
+
+        let path = PathBuf::from("/test/path/to/file.md");
+        let lines: Vec<_> = MD.lines().map(|l| l.trim().to_string()).collect();
+
+        assert!(rule_unique_footnotes(&path, &lines).is_ok());
+    }
+
+    #[test]
+    fn test_duplicate_footnote() {
+        use super::rule_unique_footnotes;
+        use crate::LintError;
+
+        const MD: &str = r#######"
+            # Heading 1
+            Some claim[^First].
+            ---
+            [^First]: First source.
+            [^First]: Duplicate source.
+            "#######;
+
+        let path = PathBuf::from("/test/path/to/file.md");
+        let lines: Vec<_> = MD.lines().map(|l| l.trim().to_string()).collect();
+
+        assert_eq!(
+            rule_unique_footnotes(&path, &lines),
+            Err(LintError::Failed {
+                path: &path,
+                line_number: 5.into(),
+                line: "[^First]: Duplicate source.".to_string(),
+                reason: "Duplicate footnote label \"First\"".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_undefined_footnote() {
+        use super::rule_unique_footnotes;
+        use crate::LintError;
+
+        const MD: &str = r#######"
+            # Heading 1
+            Some claim[^First], another claim[^Missing].
+            ---
+            [^First]: First source.
+            "#######;
+
+        let path = PathBuf::from("/test/path/to/file.md");
+        let lines: Vec<_> = MD.lines().map(|l| l.trim().to_string()).collect();
+
+        assert_eq!(
+            rule_unique_footnotes(&path, &lines),
+            Err(LintError::Failed {
+                path: &path,
+                line_number: 2.into(),
+                line: "Some claim[^First], another claim[^Missing].".to_string(),
+                reason: "Undefined footnote reference \"Missing\"".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_svg_size_limit() {
+        use super::{rule_svg_size_limit, svg_size_limit, SVG_SIZE_LIMIT_DEFAULT};
+        use crate::LintError;
+
+        let path = PathBuf::from("/test/path/to/file.svg");
+        let small: Vec<_> = vec![
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 100 100">"#.to_string(),
+            "</svg>".to_string(),
+        ];
+        let large: Vec<_> = vec![" ".repeat(SVG_SIZE_LIMIT_DEFAULT)]; // Plus implicit newline
+
+        assert!(rule_svg_size_limit(&path, &small).is_ok());
+        assert_eq!(
+            rule_svg_size_limit(&path, &large),
+            Err(LintError::Failed {
+                path: &path,
+                line_number: 0.into(),
+                line: "N/A".to_string(),
+                reason: "svg size 262145 bytes exceeds limit of 262144 bytes".to_string(),
+            })
+        );
+        assert!(svg_size_limit(&path, &small, 16).is_err());
+    }
+
+    #[test]
+    fn test_svg_aspect_ratio() {
+        use super::rule_svg_aspect_ratio;
+
+        let path = PathBuf::from("/test/path/to/file.svg");
+        let to_lines = |svg: &str| -> Vec<String> { svg.lines().map(|l| l.to_string()).collect() };
+
+        let sane =
+            to_lines(r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 400 300"></svg>"#);
+        let wide =
+            to_lines(r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 4000 30"></svg>"#);
+        let tall = to_lines(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="10px" height="500px"></svg>"#,
+        );
+
+        assert!(rule_svg_aspect_ratio(&path, &sane).is_ok());
+        assert!(rule_svg_aspect_ratio(&path, &wide).is_err());
+        assert!(rule_svg_aspect_ratio(&path, &tall).is_err());
+    }
+
+    #[test]
+    fn test_no_absolute_paths() {
+        use super::rule_no_absolute_paths;
+        use crate::LintError;
+
+        let path = PathBuf::from("/test/path/to/file.md");
+        let to_lines = |md: &str| -> Vec<String> { md.lines().map(|l| l.to_string()).collect() };
+
+        let relative = to_lines(
+            "# Title\n\nSee [the code](../code_snippets/chp2/main.rs) or <https://example.com/home/about/>.",
+        );
+        let fenced = to_lines("# Title\n\n```ignore\n$ cd /home/alice/project\n```\n\nText.");
+        let unix_home = to_lines("# Title\n\nOpen `/home/alice/book/src/chp2.md` to edit.");
+        let windows_drive = to_lines("# Title\n\nBuilt at C:\\Users\\alice\\book.");
+
+        // Ok
+        assert!(rule_no_absolute_paths(&path, &relative).is_ok());
+        assert!(rule_no_absolute_paths(&path, &fenced).is_ok());
+
+        // Error
+        assert_eq!(
+            rule_no_absolute_paths(&path, &unix_home),
+            Err(LintError::Failed {
+                path: &path,
+                line_number: 2.into(),
+                line: "Open `/home/alice/book/src/chp2.md` to edit.".to_string(),
+                reason: "Contains absolute filesystem path".to_string(),
+            })
+        );
+        assert!(rule_no_absolute_paths(&path, &windows_drive).is_err());
+    }
+
+    #[test]
+    fn test_code_fence_language() {
+        use super::{rule_code_fence_balanced, rule_code_fence_language};
+        use crate::LintError;
+
+        let path = PathBuf::from("/test/path/to/file.md");
+        let to_lines = |md: &str| -> Vec<String> { md.lines().map(|l| l.to_string()).collect() };
+
+        let tagged = to_lines("# Title\n\n```rust\nfn main() {}\n```\n\nText.");
+        let untagged = to_lines("# Title\n\n```\nfn main() {}\n```\n\nText.");
+        let unclosed = to_lines("# Title\n\n```rust\nfn main() {}\n\nText.");
+
+        // Ok
+        assert!(rule_code_fence_balanced(&path, &tagged).is_ok());
+        assert!(rule_code_fence_language(&path, &tagged).is_ok());
+
+        // Warning
+        assert!(rule_code_fence_balanced(&path, &untagged).is_ok());
+        assert_eq!(
+            rule_code_fence_language(&path, &untagged),
+            Err(LintError::Failed {
+                path: &path,
+                line_number: 2.into(),
+                line: "```".to_string(),
+                reason: "Code fence missing language identifier".to_string(),
+            })
+        );
+
+        // Error
+        assert_eq!(
+            rule_code_fence_balanced(&path, &unclosed),
+            Err(LintError::Failed {
+                path: &path,
+                line_number: 2.into(),
+                line: "```rust".to_string(),
+                reason: "Unclosed code fence".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_unique_learning_outcomes() {
+        use super::rule_unique_learning_outcomes;
+        use crate::LintError;
+
+        let path = PathBuf::from("/test/path/to/chp3/_index.md");
+        let to_lines = |md: &str| -> Vec<String> { md.lines().map(|l| l.to_string()).collect() };
+
+        let distinct =
+            to_lines("# Chapter\n\n## Learning Outcomes\n\n* Learn A\n* Learn B\n* Learn C");
+        let repeated =
+            to_lines("# Chapter\n\n## Learning Outcomes\n\n* Learn A\n* Learn B\n* Learn A");
+
+        assert!(rule_unique_learning_outcomes(&path, &distinct).is_ok());
+        assert_eq!(
+            rule_unique_learning_outcomes(&path, &repeated),
+            Err(LintError::Failed {
+                path: &path,
+                line_number: 6.into(),
+                line: "* Learn A".to_string(),
+                reason: "Duplicate learning outcome".to_string(),
+            })
+        );
+
+        // Only applies to chapter intros
+        let section_path = PathBuf::from("/test/path/to/chp3/section.md");
+        assert!(rule_unique_learning_outcomes(&section_path, &repeated).is_ok());
+    }
+
+    #[test]
+    fn test_forbid_unsafe() {
+        use super::{forbid_unsafe, rule_forbid_unsafe};
+        use crate::LintError;
+
+        let lib_path = PathBuf::from("/test/code_snippets/chp1/example/src/lib.rs");
+        let main_path = PathBuf::from("/test/code_snippets/chp1/example/src/main.rs");
+        let mod_path = PathBuf::from("/test/code_snippets/chp1/example/src/util.rs");
+        let to_lines = |rs: &str| -> Vec<String> { rs.lines().map(|l| l.to_string()).collect() };
+
+        let forbidden = to_lines("// ANCHOR: example\n#![forbid(unsafe_code)]\n\nfn main() {}");
+        let allowed = to_lines("fn main() {\n    unsafe {}\n}");
+
+        // Ok
+        assert!(rule_forbid_unsafe(&lib_path, &forbidden).is_ok());
+        assert!(rule_forbid_unsafe(&main_path, &forbidden).is_ok());
+        assert!(rule_forbid_unsafe(&mod_path, &allowed).is_ok());
+        assert!(forbid_unsafe(&main_path, &allowed, &["example/src/main.rs"]).is_ok());
+
+        // Error
+        assert_eq!(
+            rule_forbid_unsafe(&main_path, &allowed),
+            Err(LintError::Failed {
+                path: &main_path,
+                line_number: 0.into(),
+                line: "N/A".to_string(),
+                reason: "Missing `#![forbid(unsafe_code)]`".to_string(),
+            })
+        );
+        assert!(forbid_unsafe(&lib_path, &allowed, &["example/src/main.rs"]).is_err());
+    }
 }
@@ -0,0 +1,103 @@
+use crate::{
+    content::Content,
+    lint::{Level, Linter, LinterBuilder},
+    rules::*,
+    SNIPPETS_DIR_RELATIVE,
+};
+
+use std::{
+    error::Error,
+    ffi::OsStr,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use walkdir::WalkDir;
+
+/// Code snippet crates data model
+pub struct Snippets {
+    /// Rust source files, sorted by path
+    pub contents: Vec<Content>,
+}
+
+impl Snippets {
+    /// Construct a code snippets data model
+    pub fn try_new() -> Result<Self, Box<dyn Error>> {
+        Self::try_new_in(SNIPPETS_DIR_RELATIVE)
+    }
+
+    /// Construct a code snippets data model from an arbitrary directory (build output directories skipped)
+    pub fn try_new_in(snippets_dir: impl AsRef<Path>) -> Result<Self, Box<dyn Error>> {
+        let mut contents = WalkDir::new(snippets_dir)
+            .into_iter()
+            .filter_entry(|dir_entry| dir_entry.file_name() != "target")
+            .filter_map(Result::ok)
+            .filter(|dir_entry| {
+                dir_entry.file_type().is_file()
+                    && dir_entry.path().extension().and_then(OsStr::to_str) == Some("rs")
+            })
+            .map(|dir_entry| {
+                let path: PathBuf = dir_entry.path().to_path_buf();
+                let lines = fs::read_to_string(&path)?
+                    .lines()
+                    .map(str::to_string)
+                    .collect::<Vec<String>>();
+
+                Ok(Content::Source {
+                    path,
+                    hash: Content::hash_lines(&lines),
+                    lines: Some(lines),
+                })
+            })
+            .collect::<Result<Vec<Content>, Box<dyn Error>>>()?;
+
+        contents.sort_by(|a, b| a.get_path().cmp(b.get_path()));
+
+        Ok(Snippets { contents })
+    }
+
+    /// Get a linter for snippet crate roots
+    pub fn get_crate_root_linter(&self) -> Linter<'_> {
+        let mut linter = LinterBuilder::new().add_rule(Level::Warning, Rule(&rule_forbid_unsafe));
+
+        for content in self.contents.iter() {
+            linter = linter.add_content(content);
+        }
+
+        linter.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Snippets;
+    use crate::test_utils::TempFixture;
+    use crate::LeveledLintError;
+
+    #[test]
+    fn test_crate_root_linter() {
+        let fixture = TempFixture::with_files(
+            "snippets",
+            [
+                ("chp1/example/src/lib.rs", "#![forbid(unsafe_code)]\n"),
+                ("chp1/example/src/util.rs", "pub fn util() {}\n"),
+                ("chp1/example/target/main.rs", "fn main() {}\n"),
+            ],
+        );
+
+        let snippets = Snippets::try_new_in(fixture.path()).unwrap();
+        fixture.write("chp1/example/src/main.rs", "fn main() {}\n");
+        let unsafe_snippets = Snippets::try_new_in(fixture.path()).unwrap();
+
+        // Build output skipped
+        assert_eq!(snippets.contents.len(), 2);
+        assert_eq!(unsafe_snippets.contents.len(), 3);
+
+        assert_eq!(snippets.get_crate_root_linter().run(false), Ok(()));
+        assert!(matches!(
+            unsafe_snippets.get_crate_root_linter().run(false),
+            Err(LeveledLintError::Warning(_))
+        ));
+        assert_eq!(unsafe_snippets.get_crate_root_linter().run(true), Ok(()));
+    }
+}
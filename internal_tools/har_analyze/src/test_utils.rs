@@ -0,0 +1,58 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process,
+};
+
+/// Temporary directory of test fixture files, removed on drop (so also if a test panics)
+pub(crate) struct TempFixture {
+    dir: PathBuf,
+}
+
+impl TempFixture {
+    /// Create an empty fixture directory, unique per `name` and process
+    pub(crate) fn new(name: &str) -> Self {
+        let dir = env::temp_dir().join(format!("har_analyze_test_{}_{}", name, process::id()));
+
+        // Leftovers from an aborted run with the same PID
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        TempFixture { dir }
+    }
+
+    /// Create a fixture directory populated with `(relative path, contents)` files
+    pub(crate) fn with_files<'a>(
+        name: &str,
+        files: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) -> Self {
+        let fixture = Self::new(name);
+        for (path, contents) in files {
+            fixture.write(path, contents);
+        }
+
+        fixture
+    }
+
+    /// Write a file, creating parent directories as needed. Returns the file's full path.
+    pub(crate) fn write(&self, path: &str, contents: &str) -> PathBuf {
+        let path = self.dir.join(path);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).unwrap();
+        }
+        fs::write(&path, contents).unwrap();
+
+        path
+    }
+
+    /// Root of the fixture directory
+    pub(crate) fn path(&self) -> &Path {
+        &self.dir
+    }
+}
+
+impl Drop for TempFixture {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.dir);
+    }
+}
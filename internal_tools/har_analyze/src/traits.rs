@@ -4,6 +4,7 @@ use std::path::{Component, PathBuf};
 pub trait GetMetrics {
     fn get_word_count(&self) -> usize;
     fn get_diagram_count(&self) -> usize;
+    fn get_section_breakdown(&self) -> Vec<(PathBuf, usize)>;
 }
 
 /// Get chapter number
@@ -5,7 +5,7 @@ use std::{
     path::PathBuf,
 };
 
-use crate::{traits::GetMetrics, Book, Content, BOOK_SRC_DIR_RELATIVE, WORDS_PER_PAGE};
+use crate::{traits::GetMetrics, Book, Content, BOOK_SRC_DIR_RELATIVE};
 
 use separator::Separatable;
 
@@ -31,7 +31,7 @@ pub(crate) const META_TAGS: [&str; 12] = [
 // TODO: don't use `BOOK_SRC_DIR_RELATIVE`
 /// Update page/diagram count badges in book `landing.md` and `README.md`
 pub fn update_badges(book: &Book) -> io::Result<()> {
-    let page_cnt = book.get_word_count() / WORDS_PER_PAGE;
+    let page_cnt = book.get_page_count();
     let diagram_cnt = book.get_diagram_count();
     let landing_path = PathBuf::from(BOOK_SRC_DIR_RELATIVE).join("landing.md");
     let readme_path = PathBuf::from(BOOK_SRC_DIR_RELATIVE)
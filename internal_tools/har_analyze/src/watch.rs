@@ -0,0 +1,151 @@
+use crate::{status_ok, status_warn, Book, BOOK_SRC_DIR_RELATIVE};
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    error::Error,
+    path::{Path, PathBuf},
+    sync::mpsc::{self, RecvTimeoutError},
+    time::{Duration, Instant},
+};
+
+use notify::{EventKind, RecursiveMode, Watcher};
+
+/// How long watch mode blocks waiting for a filesystem event before re-checking the debouncer
+pub const WATCH_EVENT_TIMEOUT: Duration = Duration::from_millis(100);
+
+/// How long watch mode waits for changes to settle before re-linting
+pub const WATCH_DEBOUNCE_DEFAULT: Duration = Duration::from_millis(500);
+
+/// Coalesces bursts of changes (e.g. an editor's save-rename-write sequence) into a single event.
+/// A change is ready once no further change has been recorded for the quiet period.
+#[derive(Debug)]
+pub struct Debouncer {
+    quiet_period: Duration,
+    opt_last_change: Option<Instant>,
+}
+
+impl Debouncer {
+    /// Construct a debouncer with a given quiet period
+    pub fn new(quiet_period: Duration) -> Self {
+        Debouncer {
+            quiet_period,
+            opt_last_change: None,
+        }
+    }
+
+    /// Record a change observed at `now`, restarting the quiet period
+    pub fn record(&mut self, now: Instant) {
+        self.opt_last_change = Some(now);
+    }
+
+    /// Returns `true`, once per burst, if changes are pending and the quiet period has elapsed as of `now`
+    pub fn ready(&mut self, now: Instant) -> bool {
+        match self.opt_last_change {
+            Some(last_change)
+                if now.saturating_duration_since(last_change) >= self.quiet_period =>
+            {
+                self.opt_last_change = None;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Lint the book (this repo's, unless `opt_src_dir` is given), then re-lint changed files whenever
+/// a `.md`/`.svg` file changes. Runs until interrupted, lint and read failures are printed rather than returned.
+///
+/// Changes are reported by the platform's filesystem notification API (via `notify`), so nothing is polled.
+/// Only changed files are re-read, and [`Book::lint_changed`] skips any whose content is unchanged.
+pub fn watch_lint(opt_src_dir: Option<&Path>, log_warn: bool) -> Result<(), Box<dyn Error>> {
+    let src_dir = opt_src_dir.unwrap_or(Path::new(BOOK_SRC_DIR_RELATIVE));
+    let mut debouncer = Debouncer::new(WATCH_DEBOUNCE_DEFAULT);
+    let mut book = Book::try_new_in(src_dir, true)?;
+    let mut linted_hashes = BTreeMap::new();
+    let mut changed_paths = BTreeSet::new();
+
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx)?;
+    watcher.watch(src_dir, RecursiveMode::Recursive)?;
+
+    println!("Watching {:?} for changes...", src_dir);
+    lint_changed(&book, &mut linted_hashes, log_warn);
+
+    loop {
+        match rx.recv_timeout(WATCH_EVENT_TIMEOUT) {
+            // Reads (including our own re-reads) aren't changes
+            Ok(Ok(event)) if !matches!(event.kind, EventKind::Access(_)) => {
+                for path in event.paths.into_iter().filter(|p| Book::is_content_path(p)) {
+                    changed_paths.insert(path);
+                    debouncer.record(Instant::now());
+                }
+            }
+            Ok(Ok(_)) | Err(RecvTimeoutError::Timeout) => {}
+            Ok(Err(err)) => println!("{}", status_warn(&format!("Watch error: {}", err))),
+            Err(RecvTimeoutError::Disconnected) => return Err("File watcher stopped".into()),
+        }
+
+        if debouncer.ready(Instant::now()) {
+            let paths: Vec<PathBuf> = changed_paths.iter().cloned().collect();
+            match book.refresh(&paths) {
+                Ok(()) => {
+                    changed_paths.clear();
+                    lint_changed(&book, &mut linted_hashes, log_warn);
+                }
+                // Paths kept, so they're re-read on the next change
+                Err(err) => println!("{}", status_warn(&format!("Re-read failed: {}", err))),
+            }
+        }
+    }
+}
+
+// Lint contents changed since the last successful run, printing the result
+fn lint_changed(book: &Book, linted_hashes: &mut BTreeMap<PathBuf, u64>, log_warn: bool) {
+    let changed_cnt = book
+        .get_content_hashes()
+        .iter()
+        .filter(|(path, hash)| linted_hashes.get(*path) != Some(*hash))
+        .count();
+
+    println!("Linting {} changed file(s)...", changed_cnt);
+    match book.lint_changed(linted_hashes, log_warn) {
+        Ok(hashes) => {
+            println!("{}", status_ok("Lint"));
+            *linted_hashes = hashes;
+        }
+        // Hashes not updated, so failing files are re-linted on the next change
+        Err(err) => println!("{:?}", err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Debouncer;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn test_debouncer() {
+        let quiet_period = Duration::from_millis(500);
+        let ms = Duration::from_millis;
+        let start = Instant::now();
+        let mut debouncer = Debouncer::new(quiet_period);
+
+        // Nothing pending
+        assert!(!debouncer.ready(start + ms(1_000)));
+
+        // Burst of changes, each restarts the quiet period
+        debouncer.record(start);
+        debouncer.record(start + ms(200));
+        debouncer.record(start + ms(400));
+        assert!(!debouncer.ready(start + ms(600)));
+        assert!(!debouncer.ready(start + ms(899)));
+
+        // Settled, fires once
+        assert!(debouncer.ready(start + ms(900)));
+        assert!(!debouncer.ready(start + ms(2_000)));
+
+        // Next change
+        debouncer.record(start + ms(3_000));
+        assert!(debouncer.ready(start + ms(3_500)));
+    }
+}